@@ -83,6 +83,9 @@ pub enum Command {
     /// Processes an input file to extract provided metadata
     Query(QueryCommand),
 
+    /// Evaluates code interactively with the definitions of an input file
+    Repl(ReplCommand),
+
     /// Lists all discovered fonts in system and custom font paths
     Fonts(FontsCommand),
 
@@ -139,7 +142,7 @@ pub struct CompileCommand {
     pub open: Option<Option<String>>,
 
     /// The PPI (pixels per inch) to use for PNG export
-    #[arg(long = "ppi", default_value_t = 144.0)]
+    #[arg(long = "ppi", default_value_t = 144.0, value_parser = parse_ppi)]
     pub ppi: f32,
 
     /// Produces performance timings of the compilation process (experimental)
@@ -154,6 +157,10 @@ pub struct CompileCommand {
     /// conformance with.
     #[arg(long = "pdf-standard", value_delimiter = ',')]
     pub pdf_standard: Vec<PdfStandard>,
+
+    /// How to handle fonts whose license restricts embedding them into a PDF
+    #[arg(long = "restricted-fonts", default_value_t = RestrictedFonts::Warn)]
+    pub restricted_fonts: RestrictedFonts,
 }
 
 /// A PDF standard that Typst can enforce conformance with.
@@ -168,6 +175,26 @@ pub enum PdfStandard {
     A_2b,
 }
 
+/// How to handle fonts whose license restricts embedding them into a PDF.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum RestrictedFonts {
+    /// Embed them anyway.
+    Embed,
+    /// Embed them, but emit a warning.
+    Warn,
+    /// Fail the export.
+    Refuse,
+}
+
+impl Display for RestrictedFonts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
 /// Initializes a new project from a template
 #[derive(Debug, Clone, Parser)]
 pub struct InitCommand {
@@ -216,6 +243,22 @@ pub struct QueryCommand {
     pub pretty: bool,
 }
 
+/// Evaluates code interactively with the definitions of an input file
+#[derive(Debug, Clone, Parser)]
+pub struct ReplCommand {
+    /// Shared arguments
+    #[clap(flatten)]
+    pub common: SharedArgs,
+
+    /// Render the first page of each content result to this PNG file
+    #[clap(long = "preview", value_name = "PATH")]
+    pub preview: Option<PathBuf>,
+
+    /// The PPI (pixels per inch) to use for the preview
+    #[arg(long = "ppi", default_value_t = 144.0, value_parser = parse_ppi)]
+    pub ppi: f32,
+}
+
 // Output file format for query command
 #[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
 pub enum SerializationFormat {
@@ -223,7 +266,7 @@ pub enum SerializationFormat {
     Yaml,
 }
 
-/// Common arguments of compile, watch, and query.
+/// Common arguments of compile, watch, query, and repl.
 #[derive(Debug, Clone, Args)]
 pub struct SharedArgs {
     /// Path to input Typst file. Use `-` to read input from stdin
@@ -421,6 +464,15 @@ impl FromStr for PageRangeArgument {
     }
 }
 
+fn parse_ppi(value: &str) -> Result<f32, &'static str> {
+    let ppi = f32::from_str(value).map_err(|_| "not a valid number")?;
+    if ppi.is_finite() && ppi > 0.0 {
+        Ok(ppi)
+    } else {
+        Err("must be a positive number")
+    }
+}
+
 fn parse_page_number(value: &str) -> Result<NonZeroUsize, &'static str> {
     if value == "0" {
         Err("page numbers start at one")
@@ -465,7 +517,9 @@ pub struct FontArgs {
 /// Which format to use for diagnostics.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
 pub enum DiagnosticFormat {
+    /// Annotated source snippets with line numbers, hints, and call traces.
     Human,
+    /// One line per diagnostic with its file, line, and column.
     Short,
 }
 
@@ -510,6 +564,7 @@ pub enum OutputFormat {
     Pdf,
     Png,
     Svg,
+    Txt,
 }
 
 impl Display for OutputFormat {
@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use chrono::{Datelike, Timelike};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::term;
-use ecow::{eco_format, EcoString};
+use ecow::{eco_format, EcoString, EcoVec};
 use parking_lot::RwLock;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use typst::diag::{
@@ -20,7 +20,7 @@ use typst_pdf::{PdfOptions, PdfStandards};
 
 use crate::args::{
     CompileCommand, DiagnosticFormat, Input, Output, OutputFormat, PageRangeArgument,
-    PdfStandard,
+    PdfStandard, RestrictedFonts,
 };
 use crate::timings::Timer;
 use crate::watch::Status;
@@ -42,6 +42,7 @@ impl CompileCommand {
                     OutputFormat::Pdf => "pdf",
                     OutputFormat::Png => "png",
                     OutputFormat::Svg => "svg",
+                    OutputFormat::Txt => "txt",
                 },
             ))
         })
@@ -58,6 +59,12 @@ impl CompileCommand {
                 Some(ext) if ext.eq_ignore_ascii_case("pdf") => OutputFormat::Pdf,
                 Some(ext) if ext.eq_ignore_ascii_case("png") => OutputFormat::Png,
                 Some(ext) if ext.eq_ignore_ascii_case("svg") => OutputFormat::Svg,
+                Some(ext) if ext.eq_ignore_ascii_case("txt") => OutputFormat::Txt,
+                Some(ext) if ext.eq_ignore_ascii_case("html") => bail!(
+                    "cannot export to {}, HTML export is not supported yet.\n\
+                     consider exporting to PDF, PNG, or SVG instead",
+                    output.display()
+                ),
                 _ => bail!(
                     "could not infer output format for path {}.\n\
                      consider providing the format manually with `--format/-f`",
@@ -119,8 +126,10 @@ pub fn compile_once(
         Status::Compiling.print(command).unwrap();
     }
 
-    let Warned { output, warnings } = typst::compile(world);
-    let result = output.and_then(|document| export(world, &document, command, watching));
+    let Warned { output, mut warnings } = typst::compile(world);
+    let result = output
+        .and_then(|document| export(world, &document, command, watching))
+        .map(|export_warnings| warnings.extend(export_warnings));
 
     match result {
         // Export the PDF / PNG.
@@ -168,28 +177,33 @@ pub fn compile_once(
     Ok(())
 }
 
-/// Export into the target format.
+/// Export into the target format and return the warnings of the export.
 fn export(
     world: &mut SystemWorld,
     document: &Document,
     command: &CompileCommand,
     watching: bool,
-) -> SourceResult<()> {
+) -> SourceResult<EcoVec<SourceDiagnostic>> {
     match command.output_format().at(Span::detached())? {
         OutputFormat::Png => {
             export_image(world, document, command, watching, ImageExportFormat::Png)
-                .at(Span::detached())
+                .at(Span::detached())?
         }
         OutputFormat::Svg => {
             export_image(world, document, command, watching, ImageExportFormat::Svg)
-                .at(Span::detached())
+                .at(Span::detached())?
         }
-        OutputFormat::Pdf => export_pdf(document, command),
+        OutputFormat::Pdf => return export_pdf(document, command),
+        OutputFormat::Txt => export_text(document, command).at(Span::detached())?,
     }
+    Ok(EcoVec::new())
 }
 
 /// Export to a PDF.
-fn export_pdf(document: &Document, command: &CompileCommand) -> SourceResult<()> {
+fn export_pdf(
+    document: &Document,
+    command: &CompileCommand,
+) -> SourceResult<EcoVec<SourceDiagnostic>> {
     let options = PdfOptions {
         ident: Smart::Auto,
         timestamp: convert_datetime(
@@ -197,14 +211,27 @@ fn export_pdf(document: &Document, command: &CompileCommand) -> SourceResult<()>
         ),
         page_ranges: command.exported_page_ranges(),
         standards: command.pdf_standards().at(Span::detached())?,
+        restricted_fonts: match command.restricted_fonts {
+            RestrictedFonts::Embed => typst_pdf::RestrictedFonts::Embed,
+            RestrictedFonts::Warn => typst_pdf::RestrictedFonts::Warn,
+            RestrictedFonts::Refuse => typst_pdf::RestrictedFonts::Refuse,
+        },
     };
-    let buffer = typst_pdf::pdf(document, &options)?;
+    let Warned { output: buffer, warnings } = typst_pdf::pdf_warned(document, &options)?;
     command
         .output()
         .write(&buffer)
         .map_err(|err| eco_format!("failed to write PDF file ({err})"))
         .at(Span::detached())?;
-    Ok(())
+    Ok(warnings)
+}
+
+/// Export the plain text of the document, with pages separated by form feeds.
+fn export_text(document: &Document, command: &CompileCommand) -> StrResult<()> {
+    command
+        .output()
+        .write(document.text().as_bytes())
+        .map_err(|err| eco_format!("failed to write text file ({err})"))
 }
 
 /// Convert [`chrono::DateTime`] to [`Datetime`]
@@ -6,6 +6,7 @@ mod greet;
 mod init;
 mod package;
 mod query;
+mod repl;
 mod terminal;
 mod timings;
 #[cfg(feature = "self-update")]
@@ -63,6 +64,7 @@ fn dispatch() -> HintedStrResult<()> {
         Command::Watch(command) => crate::watch::watch(timer, command.clone())?,
         Command::Init(command) => crate::init::init(command)?,
         Command::Query(command) => crate::query::query(command)?,
+        Command::Repl(command) => crate::repl::repl(command)?,
         Command::Fonts(command) => crate::fonts::fonts(command),
         Command::Update(command) => crate::update::update(command)?,
     }
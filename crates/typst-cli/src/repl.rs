@@ -0,0 +1,240 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::path::Path;
+
+use comemo::Track;
+use ecow::eco_format;
+use typst::diag::{bail, HintedStrResult, SourceDiagnostic, StrResult, Warned};
+use typst::engine::{Route, Sink, Traced};
+use typst::foundations::{Content, Repr, Scope, Smart, Value};
+use typst::layout::{Abs, Margin, PageElem};
+use typst::syntax::{parse_code, FileId, Source, Span, VirtualPath};
+use typst::World;
+use typst_eval::{eval, eval_string_scoped};
+
+use crate::args::{Input, ReplCommand};
+use crate::compile::print_diagnostics;
+use crate::world::SystemWorld;
+
+/// Execute an interactive evaluation session.
+pub fn repl(command: &ReplCommand) -> HintedStrResult<()> {
+    if matches!(command.common.input, Input::Stdin) {
+        bail!("the REPL cannot read the input file from stdin");
+    }
+
+    let mut world = SystemWorld::new(&command.common)?;
+
+    // Reset everything and ensure that the main file is present.
+    world.reset();
+    let source = world.source(world.main()).map_err(|err| err.to_string())?;
+
+    // Evaluate the input file to make its definitions available.
+    let mut sink = Sink::new();
+    let traced = Traced::default();
+    let result = eval(
+        &typst::ROUTINES,
+        (&world as &dyn World).track(),
+        traced.track(),
+        sink.track_mut(),
+        Route::default().track(),
+        &source,
+    );
+
+    let warnings = sink.warnings();
+    let mut scope = match result {
+        Ok(module) => {
+            print(&world, command, &[], &warnings)?;
+            module.scope().clone()
+        }
+        Err(errors) => {
+            print(&world, command, &errors, &warnings)?;
+            bail!("failed to evaluate the input file");
+        }
+    };
+
+    // The lines of an input that is not yet complete.
+    let mut pending = String::new();
+    let mut count = 0;
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        eprint!("{}", if pending.is_empty() { "> " } else { ". " });
+        io::stderr().flush().map_err(|err| eco_format!("{err}"))?;
+
+        let Some(line) = lines.next() else { break };
+        let line = line.map_err(|err| eco_format!("failed to read input ({err})"))?;
+        if !accumulate(&mut pending, &line) {
+            continue;
+        }
+
+        count += 1;
+        evaluate(&world, command, &mut scope, mem::take(&mut pending), count)?;
+    }
+
+    Ok(())
+}
+
+/// Adds a line to the pending input and returns whether the input is ready
+/// for evaluation.
+///
+/// Inputs with unclosed delimiters, strings, or raw blocks continue on the
+/// next line. An empty line submits the input regardless, so that its errors
+/// are reported.
+fn accumulate(pending: &mut String, line: &str) -> bool {
+    if line.trim().is_empty() {
+        return !pending.is_empty();
+    }
+
+    if !pending.is_empty() {
+        pending.push('\n');
+    }
+
+    pending.push_str(line);
+    !is_incomplete(pending)
+}
+
+/// Whether the code only fails to parse because it ends too early.
+fn is_incomplete(text: &str) -> bool {
+    parse_code(text).errors().iter().any(|error| {
+        matches!(
+            error.message.as_str(),
+            "unclosed delimiter" | "unclosed string" | "unclosed raw text"
+        )
+    })
+}
+
+/// Evaluate one input against the scope, print its result, and keep its
+/// bindings.
+fn evaluate(
+    world: &SystemWorld,
+    command: &ReplCommand,
+    scope: &mut Scope,
+    text: String,
+    count: usize,
+) -> StrResult<()> {
+    // Register the input as a source file so that diagnostics can point into
+    // it.
+    let id = FileId::new_fake(VirtualPath::new(format!("<repl:{count}>")));
+    let source = Source::new(id, text);
+    let span = Span::from_range(id, 0..source.len_bytes());
+    world.insert_source(source.clone());
+
+    let mut sink = Sink::new();
+    let result = eval_string_scoped(
+        &typst::ROUTINES,
+        (world as &dyn World).track(),
+        sink.track_mut(),
+        source.text(),
+        span,
+        scope.clone(),
+    );
+
+    let warnings = sink.warnings();
+    let value = match result {
+        Ok((value, extended)) => {
+            *scope = extended;
+            value
+        }
+        Err(errors) => return print(world, command, &errors, &warnings),
+    };
+
+    print(world, command, &[], &warnings)?;
+
+    if let (Value::Content(content), Some(path)) = (&value, &command.preview) {
+        preview(world, command, content, path)
+    } else {
+        if value != Value::None {
+            println!("{}", value.repr());
+        }
+        Ok(())
+    }
+}
+
+/// Lay out content on a page that fits it and render the first page to a PNG
+/// file.
+fn preview(
+    world: &SystemWorld,
+    command: &ReplCommand,
+    content: &Content,
+    path: &Path,
+) -> StrResult<()> {
+    let margin = Margin::splat(Some(Smart::Custom(Abs::pt(10.0).into())));
+    let content = content
+        .clone()
+        .styled(PageElem::set_width(Smart::Auto))
+        .styled(PageElem::set_height(Smart::Auto))
+        .styled(PageElem::set_margin(margin));
+
+    let Warned { output, warnings } = typst::layout_content(world, &content);
+    let document = match output {
+        Ok(document) => document,
+        Err(errors) => return print(world, command, &errors, &warnings),
+    };
+
+    print(world, command, &[], &warnings)?;
+
+    let Some(page) = document.pages.first() else { return Ok(()) };
+    let pixmap = typst_render::render(page, command.ppi / 72.0);
+    let buf = pixmap
+        .encode_png()
+        .map_err(|err| eco_format!("failed to encode PNG file ({err})"))?;
+    fs::write(path, buf)
+        .map_err(|err| eco_format!("failed to write PNG file ({err})"))?;
+
+    eprintln!("rendered preview to {}", path.display());
+    Ok(())
+}
+
+/// Print diagnostics to the terminal.
+fn print(
+    world: &SystemWorld,
+    command: &ReplCommand,
+    errors: &[SourceDiagnostic],
+    warnings: &[SourceDiagnostic],
+) -> StrResult<()> {
+    print_diagnostics(world, errors, warnings, command.common.diagnostic_format)
+        .map_err(|err| eco_format!("failed to print diagnostics ({err})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accumulate;
+
+    /// Feed lines to the accumulator and collect the inputs it submits.
+    fn inputs(lines: &[&str]) -> Vec<String> {
+        let mut pending = String::new();
+        let mut out = vec![];
+        for line in lines {
+            if accumulate(&mut pending, line) {
+                out.push(std::mem::take(&mut pending));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_accumulate_single_lines() {
+        assert_eq!(inputs(&["1 + 2", "", "let x = 1"]), ["1 + 2", "let x = 1"]);
+    }
+
+    #[test]
+    fn test_accumulate_unclosed() {
+        assert_eq!(
+            inputs(&["let f(x) = {", "  x + 1", "}", "f(1)"]),
+            ["let f(x) = {\n  x + 1\n}", "f(1)"]
+        );
+        assert_eq!(inputs(&["(1,", "2)"]), ["(1,\n2)"]);
+        assert_eq!(inputs(&["\"a", "b\""]), ["\"a\nb\""]);
+    }
+
+    #[test]
+    fn test_accumulate_empty_line_submits() {
+        assert_eq!(inputs(&["[unclosed", "", "1"]), ["[unclosed", "1"]);
+    }
+
+    #[test]
+    fn test_accumulate_keeps_other_errors() {
+        assert_eq!(inputs(&["1 +"]), ["1 +"]);
+    }
+}
@@ -20,6 +20,9 @@ use crate::world::{SystemWorld, WorldCreationError};
 use crate::{print_error, terminal};
 
 /// Execute a watching compilation command.
+///
+/// Recompilations are incremental: Results of the previous compilations that
+/// do not depend on the changed files are reused from the memoization cache.
 pub fn watch(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
     let Output::Path(output) = command.output() else {
         bail!("cannot write document to stdout in watch mode");
@@ -63,7 +66,8 @@ pub fn watch(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
         // Recompile.
         timer.record(&mut world, |world| compile_once(world, &mut command, true))??;
 
-        // Evict the cache.
+        // Evict cache entries that were not used in the last ten
+        // compilations.
         comemo::evict(10);
 
         // Adjust the file watching.
@@ -156,6 +156,14 @@ impl SystemWorld {
         self.workdir.as_deref().unwrap_or(Path::new("."))
     }
 
+    /// Make a source file available under its id without reading it from
+    /// disk, e.g. for code entered interactively.
+    ///
+    /// The source is only retained until the next [`reset`](Self::reset).
+    pub fn insert_source(&self, source: Source) {
+        self.slot(source.id(), |slot| slot.source.insert(source));
+    }
+
     /// Return all paths the last compilation depended on.
     pub fn dependencies(&mut self) -> impl Iterator<Item = PathBuf> + '_ {
         self.slots
@@ -338,6 +346,12 @@ impl<T: Clone> SlotCell<T> {
         self.accessed = false;
     }
 
+    /// Fills the cell with the given data and marks it as accessed.
+    fn insert(&mut self, data: T) {
+        self.data = Some(Ok(data));
+        self.accessed = true;
+    }
+
     /// Gets the contents of the cell or initialize them.
     fn get_or_init(
         &mut self,
@@ -1,5 +1,5 @@
-use ecow::{eco_vec, EcoVec};
-use typst_library::diag::{bail, error, At, SourceResult};
+use ecow::{eco_format, eco_vec, EcoVec};
+use typst_library::diag::{bail, error, At, HintedString, SourceResult};
 use typst_library::foundations::{
     ops, Array, Capturer, Closure, Content, ContextElem, Dict, Func, NativeElement, Str,
     Value,
@@ -306,7 +306,7 @@ impl Eval for ast::FieldAccess<'_> {
         let value = self.target().eval(vm)?;
         let field = self.field();
 
-        let err = match value.field(&field).at(field.span()) {
+        let err = match value.field(&field) {
             Ok(value) => return Ok(value),
             Err(err) => err,
         };
@@ -329,7 +329,16 @@ impl Eval for ast::FieldAccess<'_> {
             }
         }
 
-        Err(err)
+        // Check whether a method call is missing its parentheses.
+        let mut err = HintedString::from(err);
+        if matches!(value.ty().scope().get(&field), Some(Value::Func(_))) {
+            let name = field.as_str();
+            err.hint(eco_format!(
+                "to call the method `{name}`, add parentheses: `{name}()`"
+            ));
+        }
+
+        Err(err).at(field.span())
     }
 }
 
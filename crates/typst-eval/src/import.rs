@@ -4,7 +4,7 @@ use typst_library::diag::{
     bail, error, warning, At, FileError, SourceResult, Trace, Tracepoint,
 };
 use typst_library::engine::Engine;
-use typst_library::foundations::{Content, Module, Value};
+use typst_library::foundations::{Content, Module, Scope, Value};
 use typst_library::World;
 use typst_syntax::ast::{self, AstNode};
 use typst_syntax::package::{PackageManifest, PackageSpec};
@@ -79,7 +79,11 @@ impl Eval for ast::ModuleImport<'_> {
 
                     while let Some(component) = &path.next() {
                         let Some(value) = scope.get(component) else {
-                            errors.push(error!(component.span(), "unresolved import"));
+                            let mut error = error!(component.span(), "unresolved import");
+                            if let Some(similar) = similar_name(scope, component) {
+                                error.hint(eco_format!("did you mean `{similar}`?"));
+                            }
+                            errors.push(error);
                             break;
                         };
 
@@ -149,7 +153,10 @@ impl Eval for ast::ModuleInclude<'_> {
         let span = self.source().span();
         let source = self.source().eval(vm)?;
         let module = match source {
-            Value::Str(path) => import(&mut vm.engine, &path, span)?,
+            Value::Str(path) => {
+                check_cyclic_include(&vm.engine, &path, span)?;
+                import(&mut vm.engine, &path, span)?
+            }
             Value::Module(module) => module,
             v => bail!(span, "expected path or module, found {}", v.ty()),
         };
@@ -157,6 +164,14 @@ impl Eval for ast::ModuleInclude<'_> {
     }
 }
 
+/// Finds a definition in the scope whose name only differs from the given one
+/// in case or in the use of underscores instead of hyphens (or vice versa).
+fn similar_name<'a>(scope: &'a Scope, name: &str) -> Option<&'a EcoString> {
+    let normalize = |s: &str| s.to_lowercase().replace('_', "-");
+    let goal = normalize(name);
+    scope.iter().map(|(var, ..)| var).find(|var| normalize(var) == goal)
+}
+
 /// Process an import of a package or file relative to the current location.
 pub fn import(engine: &mut Engine, from: &str, span: Span) -> SourceResult<Module> {
     if from.starts_with('@') {
@@ -168,6 +183,24 @@ pub fn import(engine: &mut Engine, from: &str, span: Span) -> SourceResult<Modul
     }
 }
 
+/// Reports a cyclic include with a more fitting message than the one for
+/// cyclic imports.
+fn check_cyclic_include(engine: &Engine, from: &str, span: Span) -> SourceResult<()> {
+    if from.starts_with('@') {
+        return Ok(());
+    }
+
+    let id = span.resolve_path(from).at(span)?;
+    if engine.route.contains(id) {
+        bail!(
+            span, "cyclic include";
+            hint: "a file cannot include itself, neither directly nor through other files"
+        );
+    }
+
+    Ok(())
+}
+
 /// Import a file from a path. The path is resolved relative to the given
 /// `span`.
 fn import_file(engine: &mut Engine, id: FileId, span: Span) -> SourceResult<Module> {
@@ -24,6 +24,8 @@ use self::access::*;
 use self::binding::*;
 use self::methods::*;
 
+use std::ops::Range;
+
 use comemo::{Track, Tracked, TrackedMut};
 use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::{Engine, Route, Sink, Traced};
@@ -32,7 +34,10 @@ use typst_library::introspection::Introspector;
 use typst_library::math::EquationElem;
 use typst_library::routines::Routines;
 use typst_library::World;
-use typst_syntax::{ast, parse, parse_code, parse_math, Source, Span};
+use typst_syntax::{
+    ast, parse, parse_code, parse_math, FileId, LinkedNode, Source, Span, SyntaxError,
+    SyntaxKind,
+};
 
 /// Evaluate a source file and return the resulting module.
 #[comemo::memoize]
@@ -106,6 +111,38 @@ pub fn eval_string(
     mode: EvalMode,
     scope: Scope,
 ) -> SourceResult<Value> {
+    let mut sink = Sink::new();
+    eval_string_impl(routines, world, sink.track_mut(), string, span, mode, scope)
+        .map(|(output, _)| output)
+}
+
+/// Evaluate a string as code and return the resulting value along with the
+/// given scope, extended by the bindings the code defines.
+///
+/// Unlike [`eval_string`], this is not memoized and reports warnings to the
+/// given sink. This makes it suitable for interactive sessions, which evaluate
+/// one input after another and carry the bindings over.
+pub fn eval_string_scoped(
+    routines: &Routines,
+    world: Tracked<dyn World + '_>,
+    sink: TrackedMut<Sink>,
+    string: &str,
+    span: Span,
+    scope: Scope,
+) -> SourceResult<(Value, Scope)> {
+    eval_string_impl(routines, world, sink, string, span, EvalMode::Code, scope)
+}
+
+/// The shared implementation of [`eval_string`] and [`eval_string_scoped`].
+fn eval_string_impl(
+    routines: &Routines,
+    world: Tracked<dyn World + '_>,
+    sink: TrackedMut<Sink>,
+    string: &str,
+    span: Span,
+    mode: EvalMode,
+    scope: Scope,
+) -> SourceResult<(Value, Scope)> {
     let mut root = match mode {
         EvalMode::Code => parse_code(string),
         EvalMode::Markup => parse(string),
@@ -114,14 +151,28 @@ pub fn eval_string(
 
     root.synthesize(span);
 
-    // Check for well-formedness.
+    // Check for well-formedness. If the string directly comes from a string
+    // literal or a source file, we point the errors into it so that they are
+    // reported at their exact position. Otherwise, they point to the string as
+    // a whole.
     let errors = root.errors();
     if !errors.is_empty() {
+        if let Some((id, offset)) = literal_offset(world, string, span) {
+            let mut ranged = vec![];
+            ranged_errors(&LinkedNode::new(&root), &mut ranged);
+            return Err(ranged
+                .into_iter()
+                .map(|(range, mut error)| {
+                    error.span =
+                        Span::from_range(id, offset + range.start..offset + range.end);
+                    error.into()
+                })
+                .collect());
+        }
         return Err(errors.into_iter().map(Into::into).collect());
     }
 
     // Prepare the engine.
-    let mut sink = Sink::new();
     let introspector = Introspector::default();
     let traced = Traced::default();
     let engine = Engine {
@@ -129,7 +180,7 @@ pub fn eval_string(
         world,
         introspector: introspector.track(),
         traced: traced.track(),
-        sink: sink.track_mut(),
+        sink,
         route: Route::default(),
     };
 
@@ -157,7 +208,50 @@ pub fn eval_string(
         bail!(flow.forbidden());
     }
 
-    Ok(output)
+    // Hand back the scope along with the bindings made at the top level.
+    let mut scope = vm.scopes.scopes.pop().unwrap_or_default();
+    scope.extend(std::mem::take(&mut vm.scopes.top));
+
+    Ok((output, scope))
+}
+
+/// Determines the file and byte offset of the string's text if the span points
+/// to a string literal containing it verbatim, i.e. without escape sequences,
+/// or to a range of a source file holding exactly the string.
+fn literal_offset(
+    world: Tracked<dyn World + '_>,
+    string: &str,
+    span: Span,
+) -> Option<(FileId, usize)> {
+    let id = span.id()?;
+    let source = world.source(id).ok()?;
+    if let Some(range) = span.range() {
+        return (source.get(range.clone())? == string).then_some((id, range.start));
+    }
+
+    let node = source.find(span)?;
+    if node.kind() != SyntaxKind::Str {
+        return None;
+    }
+
+    let inner = node.text().strip_prefix('"')?.strip_suffix('"')?;
+    (inner == string).then(|| (id, node.offset() + 1))
+}
+
+/// Collects the syntax errors below a node along with the byte ranges of the
+/// erroneous nodes.
+fn ranged_errors(node: &LinkedNode, out: &mut Vec<(Range<usize>, SyntaxError)>) {
+    if !node.erroneous() {
+        return;
+    }
+
+    if node.kind() == SyntaxKind::Error {
+        out.extend(node.errors().into_iter().map(|error| (node.range(), error)));
+    } else {
+        for child in node.children() {
+            ranged_errors(&child, out);
+        }
+    }
 }
 
 /// Evaluate an expression.
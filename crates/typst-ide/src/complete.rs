@@ -30,6 +30,13 @@ use crate::{analyze_expr, analyze_import, analyze_labels, named_items, IdeWorld}
 /// Returns the position from which the completions apply and a list of
 /// completions.
 ///
+/// The completions depend on the syntactic context of the cursor: In argument
+/// lists, they include the parameters of the called function that were not
+/// given yet and the values a named parameter accepts. Elsewhere, they include
+/// the fields and methods of the value before a dot, the labels in the
+/// document, the items of an imported module, and the definitions in scope,
+/// each with a short description when available.
+///
 /// When `explicit` is `true`, the user requested the completion by pressing
 /// control and space or something similar.
 ///
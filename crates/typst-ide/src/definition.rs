@@ -167,6 +167,13 @@ mod tests {
         test_with_world(world, -1, Side::After).must_be_at("other.typ", 5..6);
     }
 
+    #[test]
+    fn test_definition_template() {
+        let world = TestWorld::new("#import \"template.typ\": conf\n#show: conf")
+            .with_source("template.typ", "#let conf(doc) = doc");
+        test_with_world(world, -1, Side::After).must_be_at("template.typ", 5..9);
+    }
+
     #[test]
     fn test_definition_import() {
         let world = TestWorld::new("#import \"other.typ\" as o: x")
@@ -28,6 +28,11 @@ impl Jump {
 }
 
 /// Determine where to jump to based on a click in a frame.
+///
+/// This implements inverse search: A viewer that displays the document can
+/// use it to move the editor's cursor to the source of the clicked text. The
+/// click is given in the coordinate system of the frame, which usually is a
+/// page's frame.
 pub fn jump_from_click(
     world: &dyn IdeWorld,
     document: &Document,
@@ -53,9 +58,10 @@ pub fn jump_from_click(
     for (mut pos, item) in frame.items().rev() {
         match item {
             FrameItem::Group(group) => {
-                // TODO: Handle transformation.
-                if let Some(span) =
-                    jump_from_click(world, document, &group.frame, click - pos)
+                // Map the click into the group's coordinate system.
+                let Some(inverse) = group.transform.invert() else { continue };
+                let local = (click - pos).transform(inverse);
+                if let Some(span) = jump_from_click(world, document, &group.frame, local)
                 {
                     return Some(span);
                 }
@@ -109,6 +115,10 @@ pub fn jump_from_click(
 }
 
 /// Find the output location in the document for a cursor position.
+///
+/// This implements forward search: An editor can use it to scroll a viewer to
+/// the text at the cursor. There may be multiple positions if the same source
+/// text is displayed multiple times, for instance in a header.
 pub fn jump_from_cursor(
     document: &Document,
     source: &Source,
@@ -143,9 +153,8 @@ pub fn jump_from_cursor(
 fn find_in_frame(frame: &Frame, span: Span) -> Option<Point> {
     for (mut pos, item) in frame.items() {
         if let FrameItem::Group(group) = item {
-            // TODO: Handle transformation.
             if let Some(point) = find_in_frame(&group.frame, span) {
-                return Some(point + pos);
+                return Some(point.transform(group.transform) + pos);
             }
         }
 
@@ -249,6 +258,12 @@ mod tests {
         test_click(s, point(72.0, 10.0), cursor(20));
     }
 
+    #[test]
+    fn test_jump_from_click_transformed() {
+        let s = "#scale(200%, origin: top + left)[*Hello* #box[ABC] World]";
+        test_click(s, point(80.0, 20.0), cursor(47));
+    }
+
     #[test]
     fn test_jump_from_click_par_indents() {
         // There was a bug with span mapping due to indents generating
@@ -0,0 +1,200 @@
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use ecow::EcoString;
+use typst::syntax::ast::{self, AstNode};
+use typst::syntax::{LinkedNode, Source};
+
+/// A named item in a source file, as shown in an outline of the file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Symbol {
+    /// The symbol's name.
+    pub name: EcoString,
+    /// What kind of item the symbol is.
+    pub kind: SymbolKind,
+    /// The byte range of the whole item in the source file. For headings, this
+    /// spans their whole section.
+    pub range: Range<usize>,
+    /// The byte range of the symbol's name, or of the whole heading for
+    /// headings.
+    pub selection: Range<usize>,
+    /// The symbols nested in this one.
+    pub children: Vec<Symbol>,
+}
+
+/// A kind of symbol.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SymbolKind {
+    /// A heading of the given depth.
+    Heading(NonZeroUsize),
+    /// A function defined with a let binding.
+    Function,
+    /// A variable defined with a let binding.
+    Variable,
+}
+
+/// Collect the symbols defined in a source file.
+///
+/// Headings contain the headings of greater depth and the bindings that
+/// follow them. Functions contain the bindings in their body.
+pub fn symbols(source: &Source) -> Vec<Symbol> {
+    let mut flat = vec![];
+    collect(&LinkedNode::new(source.root()), &mut flat);
+
+    // Nest the symbols into the sections of the preceding headings.
+    let mut stack: Vec<Symbol> = vec![];
+    let mut out = vec![];
+    for symbol in flat {
+        if let SymbolKind::Heading(depth) = symbol.kind {
+            while stack.last().is_some_and(
+                |open| matches!(open.kind, SymbolKind::Heading(d) if d >= depth),
+            ) {
+                close(&mut stack, &mut out, symbol.range.start);
+            }
+            stack.push(symbol);
+        } else if let Some(open) = stack.last_mut() {
+            open.children.push(symbol);
+        } else {
+            out.push(symbol);
+        }
+    }
+
+    while !stack.is_empty() {
+        close(&mut stack, &mut out, source.text().len());
+    }
+
+    out
+}
+
+/// Find the path of nested symbols that contain the cursor, from the outermost
+/// to the innermost one, as shown in a breadcrumb bar.
+pub fn symbol_path(symbols: &[Symbol], cursor: usize) -> Vec<&Symbol> {
+    let mut path = vec![];
+    let mut level = symbols;
+    while let Some(symbol) = level.iter().find(|symbol| symbol.range.contains(&cursor)) {
+        path.push(symbol);
+        level = &symbol.children;
+    }
+    path
+}
+
+/// Collect the headings and let bindings below a node.
+fn collect(node: &LinkedNode, out: &mut Vec<Symbol>) {
+    for child in node.children() {
+        if let Some(heading) = child.cast::<ast::Heading>() {
+            let body = heading.body().to_untyped().clone().into_text();
+            out.push(Symbol {
+                name: body.trim().into(),
+                kind: SymbolKind::Heading(heading.depth()),
+                range: child.range(),
+                selection: child.range(),
+                children: vec![],
+            });
+        } else if let Some(binding) = child.cast::<ast::LetBinding>() {
+            let selection = |ident: ast::Ident| {
+                child
+                    .find(ident.span())
+                    .map_or_else(|| child.range(), |node| node.range())
+            };
+
+            match binding.kind() {
+                ast::LetBindingKind::Closure(ident) => {
+                    let mut children = vec![];
+                    collect(&child, &mut children);
+                    out.push(Symbol {
+                        name: ident.get().clone(),
+                        kind: SymbolKind::Function,
+                        range: child.range(),
+                        selection: selection(ident),
+                        children,
+                    });
+                }
+                ast::LetBindingKind::Normal(pattern) => {
+                    for ident in pattern.bindings() {
+                        out.push(Symbol {
+                            name: ident.get().clone(),
+                            kind: SymbolKind::Variable,
+                            range: child.range(),
+                            selection: selection(ident),
+                            children: vec![],
+                        });
+                    }
+                }
+            }
+        } else {
+            collect(&child, out);
+        }
+    }
+}
+
+/// Pop the innermost open heading, extend its section up to `end`, and attach
+/// it to its parent.
+fn close(stack: &mut Vec<Symbol>, out: &mut Vec<Symbol>, end: usize) {
+    let Some(mut symbol) = stack.pop() else { return };
+    symbol.range.end = symbol.range.end.max(end);
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(symbol),
+        None => out.push(symbol),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::syntax::Source;
+
+    use super::{symbol_path, symbols, Symbol, SymbolKind};
+
+    /// Render the symbols as an indented list of names.
+    fn outline(text: &str) -> String {
+        fn walk(symbols: &[Symbol], indent: usize, out: &mut String) {
+            for symbol in symbols {
+                out.push_str(&" ".repeat(indent));
+                out.push_str(&symbol.name);
+                out.push('\n');
+                walk(&symbol.children, indent + 2, out);
+            }
+        }
+
+        let mut out = String::new();
+        walk(&symbols(&Source::detached(text)), 0, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_symbols_headings() {
+        let text = "= Intro\n== Details\n= Outro";
+        assert_eq!(outline(text), "Intro\n  Details\nOutro\n");
+    }
+
+    #[test]
+    fn test_symbols_bindings() {
+        let text = "#let f(x) = { let y = x; y }\n= Intro\n#let (a, b) = (1, 2)";
+        assert_eq!(outline(text), "f\n  y\nIntro\n  a\n  b\n");
+    }
+
+    #[test]
+    fn test_symbols_kinds() {
+        let symbols = symbols(&Source::detached("== Deep\n#let x = 1"));
+        assert!(
+            matches!(symbols[0].kind, SymbolKind::Heading(depth) if depth.get() == 2)
+        );
+        assert_eq!(symbols[0].children[0].kind, SymbolKind::Variable);
+        assert_eq!(symbols[0].children[0].selection, 13..14);
+        assert_eq!(symbols[0].range, 0..18);
+    }
+
+    #[test]
+    fn test_symbol_path() {
+        let source = Source::detached("= A\n== B\nText\n= C");
+        let symbols = symbols(&source);
+        let names = |cursor| {
+            symbol_path(&symbols, cursor)
+                .into_iter()
+                .map(|symbol| symbol.name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(1), ["A"]);
+        assert_eq!(names(11), ["A", "B"]);
+        assert_eq!(names(16), ["C"]);
+    }
+}
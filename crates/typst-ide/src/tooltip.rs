@@ -4,7 +4,7 @@ use ecow::{eco_format, EcoString};
 use if_chain::if_chain;
 use typst::engine::Sink;
 use typst::foundations::{repr, Capturer, CastInfo, Repr, Value};
-use typst::layout::Length;
+use typst::layout::{Angle, Length};
 use typst::model::Document;
 use typst::syntax::ast::AstNode;
 use typst::syntax::{ast, LinkedNode, Side, Source, SyntaxKind};
@@ -72,6 +72,10 @@ fn expr_tooltip(world: &dyn IdeWorld, leaf: &LinkedNode) -> Option<Tooltip> {
                 return Some(tooltip);
             }
         }
+
+        if let &Value::Angle(angle) = value {
+            return Some(angle_tooltip(angle));
+        }
     }
 
     if expr.is_literal() {
@@ -172,6 +176,15 @@ fn length_tooltip(length: Length) -> Option<Tooltip> {
     })
 }
 
+/// Tooltip text for a hovered angle.
+fn angle_tooltip(angle: Angle) -> Tooltip {
+    Tooltip::Code(eco_format!(
+        "{}deg = {}rad",
+        round_with_precision(angle.to_deg(), 2),
+        round_with_precision(angle.to_rad(), 2),
+    ))
+}
+
 /// Tooltip for a hovered reference or label.
 fn label_tooltip(document: &Document, leaf: &LinkedNode) -> Option<Tooltip> {
     let target = match leaf.kind() {
@@ -329,6 +342,11 @@ mod tests {
         test("#let x = 1 + 2", 6, Side::Before).must_be_code("3");
     }
 
+    #[test]
+    fn test_tooltip_angle() {
+        test("#let a = 90deg", 5, Side::After).must_be_code("90deg = 1.57rad");
+    }
+
     #[test]
     fn test_tooltip_empty_contextual() {
         test("#{context}", 10, Side::Before).must_be_code("context()");
@@ -149,7 +149,10 @@ impl FontSearcher {
     {
         // Font paths have highest priority.
         for path in font_dirs {
-            self.db.load_fonts_dir(path);
+            self.db.load_fonts_dir(&path);
+            // The database only knows about plain OpenType files, so WOFF
+            // files are picked up separately.
+            self.add_woff_dir(path.as_ref());
         }
 
         if self.include_system_fonts {
@@ -192,6 +195,32 @@ impl FontSearcher {
         }
     }
 
+    /// Add the WOFF fonts in a directory and its subdirectories.
+    fn add_woff_dir(&mut self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else { return };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                self.add_woff_dir(&path);
+                continue;
+            }
+
+            if !path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("woff")) {
+                continue;
+            }
+
+            let Ok(data) = fs::read(&path) else { continue };
+            if let Some(info) = FontInfo::new(&data, 0) {
+                self.book.push(info);
+                self.fonts.push(FontSlot {
+                    path: Some(path),
+                    index: 0,
+                    font: OnceLock::new(),
+                });
+            }
+        }
+    }
+
     /// Add fonts that are embedded in the binary.
     #[cfg(feature = "embed-fonts")]
     fn add_embedded(&mut self) {
@@ -1,7 +1,8 @@
 //! Download and unpack packages and package indices.
 
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
 
 use ecow::eco_format;
 use once_cell::sync::OnceCell;
@@ -30,6 +31,9 @@ pub struct PackageStorage {
     downloader: Downloader,
     /// The cached index of the preview namespace.
     index: OnceCell<Vec<PackageInfo>>,
+    /// Whether to check downloaded packages against their recorded digest
+    /// before using them from the cache.
+    verify: bool,
 }
 
 impl PackageStorage {
@@ -49,9 +53,25 @@ impl PackageStorage {
             }),
             downloader,
             index: OnceCell::new(),
+            verify: false,
         }
     }
 
+    /// Enables or disables integrity checks of cached packages.
+    ///
+    /// When a package is downloaded, a digest of its files is recorded next to
+    /// it in the cache. With integrity checks enabled, the files of a cached
+    /// package are compared against this digest before each use. Packages that
+    /// were modified, only partially written, or cached without a digest are
+    /// downloaded again. This guards against corruption and accidental
+    /// modification of a cache that is shared between builds. The digest is
+    /// not a cryptographic signature, so it does not protect against deliberate
+    /// tampering by someone with write access to the cache.
+    pub fn with_integrity_checks(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
     /// Returns the path at which non-local packages should be stored when
     /// downloaded.
     pub fn package_cache_path(&self) -> Option<&Path> {
@@ -81,7 +101,19 @@ impl PackageStorage {
         if let Some(cache_dir) = &self.package_cache_path {
             let dir = cache_dir.join(&subdir);
             if dir.exists() {
-                return Ok(dir);
+                if !self.verify
+                    || spec.namespace != "preview"
+                    || read_digest(&dir) == Some(tree_digest(&dir)?)
+                {
+                    return Ok(dir);
+                }
+
+                // Discard the damaged package and fetch it anew.
+                fs::remove_dir_all(&dir).map_err(|err| {
+                    PackageError::Other(Some(eco_format!(
+                        "failed to remove damaged package from the cache ({err})"
+                    )))
+                })?;
             }
 
             // Download from network if it doesn't exist yet.
@@ -174,10 +206,170 @@ impl PackageStorage {
             }
         };
 
-        let decompressed = flate2::read::GzDecoder::new(data.as_slice());
-        tar::Archive::new(decompressed).unpack(package_dir).map_err(|err| {
-            fs::remove_dir_all(package_dir).ok();
+        // Unpack into a temporary sibling directory first and only move it
+        // into place once it is complete. Otherwise, an interrupted unpacking
+        // would leave behind a partial package that later compilations would
+        // mistake for a cached one.
+        let temp_dir = temp_package_dir(package_dir);
+        fs::remove_dir_all(&temp_dir).ok();
+        let digest = unpack(&data, &temp_dir).map_err(|err| {
+            fs::remove_dir_all(&temp_dir).ok();
             PackageError::MalformedArchive(Some(eco_format!("{err}")))
+        })?;
+
+        // Make sure that the files on disk match the archive before exposing
+        // them to compilations.
+        if tree_digest(&temp_dir)? != digest {
+            fs::remove_dir_all(&temp_dir).ok();
+            return Err(PackageError::Other(Some(
+                "unpacked package does not match the downloaded archive".into(),
+            )));
+        }
+
+        // Record the digest before moving the package into place, so that a
+        // cached package always has one.
+        fs::write(digest_path(package_dir), format!("{digest:032x}")).map_err(|err| {
+            fs::remove_dir_all(&temp_dir).ok();
+            PackageError::Other(Some(eco_format!(
+                "failed to record package digest ({err})"
+            )))
+        })?;
+
+        if let Err(err) = fs::rename(&temp_dir, package_dir) {
+            fs::remove_dir_all(&temp_dir).ok();
+            // Another process may have prepared the same package concurrently.
+            if !package_dir.exists() {
+                return Err(PackageError::Other(Some(eco_format!(
+                    "failed to move package into the cache ({err})"
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Unpacks a gzipped package archive into a directory and returns the digest
+/// of the files it contains.
+///
+/// Only directories and regular files are unpacked. Paths are confined to the
+/// directory.
+fn unpack(data: &[u8], dir: &Path) -> std::io::Result<u128> {
+    let decompressed = flate2::read::GzDecoder::new(data);
+    let mut archive = tar::Archive::new(decompressed);
+    let mut files = vec![];
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = normalize(&entry.path()?);
+        let target = dir.join(&path);
+        let kind = entry.header().entry_type();
+        if kind.is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if kind.is_file() {
+            let mut contents = vec![];
+            entry.read_to_end(&mut contents)?;
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, &contents)?;
+            files.push((path, contents));
+        }
+    }
+    Ok(digest(files))
+}
+
+/// Computes the digest of the files below a directory.
+fn tree_digest(dir: &Path) -> PackageResult<u128> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        files: &mut Vec<(String, Vec<u8>)>,
+    ) -> std::io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, files)?;
+            } else if path.is_file() {
+                let relative = path.strip_prefix(root).unwrap_or(&path);
+                files.push((normalize(relative), fs::read(&path)?));
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = vec![];
+    walk(dir, dir, &mut files).map_err(|err| {
+        PackageError::Other(Some(eco_format!("failed to read package ({err})")))
+    })?;
+    Ok(digest(files))
+}
+
+/// Computes an order-independent digest of files and their contents.
+fn digest(mut files: Vec<(String, Vec<u8>)>) -> u128 {
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    typst_utils::hash128(&files)
+}
+
+/// Turns a relative path into a platform-independent, slash-separated string
+/// with only its normal components.
+fn normalize(path: &Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
         })
+        .collect();
+    components.join("/")
+}
+
+/// The file in which the digest of a cached package is recorded.
+fn digest_path(package_dir: &Path) -> PathBuf {
+    let name = package_dir.file_name().unwrap_or_default().to_string_lossy();
+    package_dir.with_file_name(format!(".{name}.digest"))
+}
+
+/// Reads the recorded digest of a cached package.
+fn read_digest(package_dir: &Path) -> Option<u128> {
+    let text = fs::read_to_string(digest_path(package_dir)).ok()?;
+    u128::from_str_radix(text.trim(), 16).ok()
+}
+
+/// A temporary directory next to the given package directory, which is unique
+/// to the current process.
+fn temp_package_dir(package_dir: &Path) -> PathBuf {
+    let name = package_dir.file_name().unwrap_or_default().to_string_lossy();
+    package_dir.with_file_name(format!(".{name}-{}.tmp", std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack_records_digest() {
+        let encoder = flate2::write::GzEncoder::new(vec![], Default::default());
+        let mut builder = tar::Builder::new(encoder);
+        for (path, contents) in
+            [("typst.toml", "[package]"), ("src/lib.typ", "#let x = 1")]
+        {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Regular);
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, path, contents.as_bytes()).unwrap();
+        }
+        let data = builder.into_inner().unwrap().finish().unwrap();
+
+        let dir =
+            std::env::temp_dir().join(format!("typst-kit-unpack-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        let digest = unpack(&data, &dir).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("src/lib.typ")).unwrap(), "#let x = 1");
+        assert_eq!(tree_digest(&dir).unwrap(), digest);
+
+        fs::write(dir.join("src/lib.typ"), "#let x = 2").unwrap();
+        assert_ne!(tree_digest(&dir).unwrap(), digest);
+        fs::remove_dir_all(&dir).ok();
     }
 }
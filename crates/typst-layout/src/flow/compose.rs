@@ -414,8 +414,12 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let mut separator = None;
         let mut separator_need = Abs::zero();
         if area.footnotes.is_empty() {
-            let frame =
-                layout_footnote_separator(self.engine, self.config, regions.base())?;
+            let frame = layout_footnote_separator(
+                self.engine,
+                self.config,
+                &self.config.footnote.separator,
+                regions.base(),
+            )?;
             separator_need += self.config.footnote.clearance + frame.height();
             separator = Some(frame);
         }
@@ -486,7 +490,12 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let area = &mut self.column_insertions;
 
         // Create and save the separator.
-        let separator = layout_footnote_separator(self.engine, self.config, base)?;
+        let separator = layout_footnote_separator(
+            self.engine,
+            self.config,
+            &self.config.footnote.continuation_separator,
+            base,
+        )?;
         area.push_footnote_separator(self.config, separator);
 
         // Save the footnote's frame.
@@ -515,15 +524,16 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
     }
 }
 
-/// Lay out the footnote separator, typically a line.
+/// Lay out a footnote separator, typically a line.
 fn layout_footnote_separator(
     engine: &mut Engine,
     config: &Config,
+    separator: &Content,
     base: Size,
 ) -> SourceResult<Frame> {
     crate::layout_frame(
         engine,
-        &config.footnote.separator,
+        separator,
         Locator::root(),
         config.shared,
         Region::new(base, Axes::new(config.footnote.expand, false)),
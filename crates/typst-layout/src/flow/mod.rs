@@ -87,20 +87,61 @@ pub fn layout_columns(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    layout_fragment_impl(
-        engine.routines,
-        engine.world,
-        engine.introspector,
-        engine.traced,
-        TrackedMut::reborrow_mut(&mut engine.sink),
-        engine.route.track(),
-        &elem.body,
-        locator.track(),
-        styles,
-        regions,
-        elem.count(styles),
-        elem.gutter(styles),
-    )
+    let count = elem.count(styles);
+    let gutter = elem.gutter(styles);
+    let layout = |engine: &mut Engine, regions: Regions| {
+        layout_fragment_impl(
+            engine.routines,
+            engine.world,
+            engine.introspector,
+            engine.traced,
+            TrackedMut::reborrow_mut(&mut engine.sink),
+            engine.route.track(),
+            &elem.body,
+            locator.track(),
+            styles,
+            regions,
+            count,
+            gutter,
+        )
+    };
+
+    let fragment = layout(engine, regions)?;
+    if !elem.balance(styles)
+        || count.get() == 1
+        || regions.expand.y
+        || !regions.size.y.is_finite()
+        || fragment.len() != 1
+    {
+        return Ok(fragment);
+    }
+
+    // Search for the smallest height at which the content still fits into a
+    // single region. At that height, it is distributed evenly across the
+    // columns. The probed regions repeat so that overflowing content shows up
+    // as additional frames.
+    let mut best = fragment;
+    let (mut lo, mut hi) = (Abs::zero(), regions.size.y);
+    for _ in 0..10 {
+        let mid = (lo + hi) / 2.0;
+        let probe = Regions {
+            size: Size::new(regions.size.x, mid),
+            full: regions.full,
+            backlog: &[],
+            last: Some(mid),
+            expand: regions.expand,
+        };
+
+        let fragment = layout(engine, probe)?;
+        if fragment.len() == 1 {
+            hi = mid;
+            best = fragment;
+        } else {
+            lo = mid;
+        }
+    }
+
+    Ok(best)
 }
 
 /// The cached, internal implementation of [`layout_fragment`].
@@ -191,6 +232,8 @@ pub(crate) fn layout_flow(
         },
         footnote: FootnoteConfig {
             separator: FootnoteEntry::separator_in(shared),
+            continuation_separator: FootnoteEntry::continuation_separator_in(shared)
+                .unwrap_or_else(|| FootnoteEntry::separator_in(shared)),
             clearance: FootnoteEntry::clearance_in(shared),
             gap: FootnoteEntry::gap_in(shared),
             expand: regions.expand.x,
@@ -328,6 +371,9 @@ struct Config<'x> {
 struct FootnoteConfig {
     /// The separator between flow content and footnotes. Typically a line.
     separator: Content,
+    /// The separator above a footnote that is continued from a previous
+    /// region.
+    continuation_separator: Content,
     /// The amount of space left above the separator.
     clearance: Abs,
     /// The gap between footnote entries.
@@ -341,7 +341,10 @@ impl<'a> CellGrid<'a> {
             let (header_footer_items, simple_item) = match child {
                 ResolvableGridChild::Header { repeat, span, items, .. } => {
                     if header.is_some() {
-                        bail!(span, "cannot have more than one header");
+                        bail!(
+                            span, "cannot have more than one header";
+                            hint: "a single header can span multiple rows"
+                        );
                     }
 
                     is_header = true;
@@ -364,7 +367,10 @@ impl<'a> CellGrid<'a> {
                 }
                 ResolvableGridChild::Footer { repeat, span, items, .. } => {
                     if footer.is_some() {
-                        bail!(span, "cannot have more than one footer");
+                        bail!(
+                            span, "cannot have more than one footer";
+                            hint: "a single footer can span multiple rows"
+                        );
                     }
 
                     is_footer = true;
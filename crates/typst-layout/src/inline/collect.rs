@@ -129,11 +129,12 @@ pub fn collect<'a>(
 
     let outer_dir = TextElem::dir_in(*styles);
     let first_line_indent = ParElem::first_line_indent_in(*styles);
-    if !first_line_indent.is_zero()
-        && consecutive
+    if !first_line_indent.amount.is_zero()
+        && (consecutive || first_line_indent.all)
         && AlignElem::alignment_in(*styles).resolve(*styles).x == outer_dir.start().into()
     {
-        collector.push_item(Item::Absolute(first_line_indent.resolve(*styles), false));
+        let amount = first_line_indent.amount.resolve(*styles);
+        collector.push_item(Item::Absolute(amount, false));
         collector.spans.push(1, Span::detached());
     }
 
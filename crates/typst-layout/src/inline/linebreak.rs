@@ -27,6 +27,7 @@ type Cost = f64;
 // ratios coming out differently since Typst doesn't have the concept of glue,
 // so things work a bit differently.
 const DEFAULT_HYPH_COST: Cost = 135.0;
+const DEFAULT_DASH_COST: Cost = 135.0;
 const DEFAULT_RUNT_COST: Cost = 100.0;
 
 // Other parameters.
@@ -631,7 +632,7 @@ fn raw_cost(
     // Knuth-Plass does this separately after the squaring, with a higher cost,
     // but I couldn't find any explanation as to why.
     if consecutive_dash {
-        penalty += metrics.hyph_cost;
+        penalty += metrics.dash_cost;
     }
 
     // From the Knuth-Plass Paper: $ (1 + beta_j + pi_j)^2 $.
@@ -853,6 +854,7 @@ struct CostMetrics {
     min_approx_ratio: f64,
     approx_hyphen_width: Abs,
     hyph_cost: Cost,
+    dash_cost: Cost,
     runt_cost: Cost,
 }
 
@@ -867,6 +869,7 @@ impl CostMetrics {
             approx_hyphen_width: Em::new(0.33).at(p.size),
             // Costs.
             hyph_cost: DEFAULT_HYPH_COST * p.costs.hyphenation().get(),
+            dash_cost: DEFAULT_DASH_COST * p.costs.dash().get(),
             runt_cost: DEFAULT_RUNT_COST * p.costs.runt().get(),
         }
     }
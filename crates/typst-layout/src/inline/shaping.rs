@@ -936,9 +936,13 @@ fn track_and_space(ctx: &mut ShapingContext) {
     let tracking = Em::from_length(TextElem::tracking_in(ctx.styles), ctx.size);
     let spacing =
         TextElem::spacing_in(ctx.styles).map(|abs| Em::from_length(abs, ctx.size));
+    let sentence_spacing = TextElem::sentence_spacing_in(ctx.styles)
+        .map(|abs| Em::from_length(abs, ctx.size));
 
-    let mut glyphs = ctx.glyphs.iter_mut().peekable();
-    while let Some(glyph) = glyphs.next() {
+    let sentence_spaces = sentence_spaces(&ctx.glyphs, ctx.dir);
+
+    let mut glyphs = ctx.glyphs.iter_mut().enumerate().peekable();
+    while let Some((i, glyph)) = glyphs.next() {
         // Make non-breaking space same width as normal space.
         if glyph.c == '\u{00A0}' {
             glyph.x_advance -= nbsp_delta(&glyph.font).unwrap_or_default();
@@ -946,17 +950,49 @@ fn track_and_space(ctx: &mut ShapingContext) {
 
         if glyph.is_space() {
             glyph.x_advance = spacing.relative_to(glyph.x_advance);
+            if sentence_spaces[i] {
+                glyph.x_advance = sentence_spacing.relative_to(glyph.x_advance);
+            }
         }
 
         if glyphs
             .peek()
-            .is_some_and(|next| glyph.range.start != next.range.start)
+            .is_some_and(|(_, next)| glyph.range.start != next.range.start)
         {
             glyph.x_advance += tracking;
         }
     }
 }
 
+/// Determines which glyphs are spaces directly after the end of a sentence.
+fn sentence_spaces(glyphs: &[ShapedGlyph], dir: Dir) -> Vec<bool> {
+    let mut marks = vec![false; glyphs.len()];
+    let mut sentence_end = false;
+    let mut visit = |i: usize| {
+        let c = glyphs[i].c;
+        marks[i] = sentence_end && c == ' ';
+        if !is_closing_punctuation(c) {
+            sentence_end = matches!(c, '.' | '!' | '?');
+        }
+    };
+
+    // The glyphs are in visual order, so right-to-left text is visited
+    // backwards to see it in logical order.
+    if dir.is_positive() {
+        (0..glyphs.len()).for_each(&mut visit);
+    } else {
+        (0..glyphs.len()).rev().for_each(&mut visit);
+    }
+
+    marks
+}
+
+/// Whether the character can come between the end of a sentence and the space
+/// after it.
+fn is_closing_punctuation(c: char) -> bool {
+    matches!(c, ')' | ']' | '}' | '"' | '\'' | '’' | '”' | '»' | '›')
+}
+
 /// Calculate stretchability and shrinkability of each glyph,
 /// and CJK punctuation adjustments according to Chinese Layout Requirements.
 fn calculate_adjustability(ctx: &mut ShapingContext, lang: Lang, region: Option<Region>) {
@@ -1,4 +1,9 @@
 //! Typst's layout engine.
+//!
+//! Layout is incremental: Laying out flows, paragraphs, and pages is memoized
+//! based on the content, the styles, and the available regions. When a
+//! document is compiled again, these results are reused for the parts that did
+//! not change.
 
 mod flow;
 mod grid;
@@ -145,6 +145,7 @@ fn layout_page_run_impl(
         let both = match numbering {
             Numbering::Pattern(pattern) => pattern.pieces() >= 2,
             Numbering::Func(_) => true,
+            Numbering::Symbols(_) => false,
         };
 
         let mut counter = CounterDisplayElem::new(
@@ -151,6 +151,12 @@ pub struct Warned<T> {
 ///
 /// The contained spans will only be detached if any of the input source files
 /// were detached.
+///
+/// Tools that process diagnostics programmatically should use the structured
+/// fields rather than parsing the message: The severity separates errors from
+/// warnings, the span and the trace locate the problem, and the hints carry
+/// additional advice. The message itself is meant for humans and may change
+/// between versions.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct SourceDiagnostic {
     /// Whether the diagnostic is an error or a warning.
@@ -228,6 +234,14 @@ impl From<SyntaxError> for SourceDiagnostic {
     }
 }
 
+impl std::error::Error for SourceDiagnostic {}
+
+impl Display for SourceDiagnostic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(&self.message)
+    }
+}
+
 /// A part of a diagnostic's [trace](SourceDiagnostic::trace).
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Tracepoint {
@@ -366,6 +380,14 @@ impl HintedString {
     }
 }
 
+impl std::error::Error for HintedString {}
+
+impl Display for HintedString {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.message())
+    }
+}
+
 impl<S> From<S> for HintedString
 where
     S: Into<EcoString>,
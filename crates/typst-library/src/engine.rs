@@ -47,6 +47,11 @@ impl Engine<'_> {
     }
 
     /// Runs tasks on the engine in parallel.
+    ///
+    /// The results are returned in the order of the tasks and the diagnostics
+    /// of each task are merged into this engine's sink in the same order, so
+    /// the outcome does not depend on how the tasks were scheduled. The page
+    /// runs of a document, for instance, are laid out this way.
     pub fn parallelize<P, I, T, U, F>(&mut self, iter: P, f: F) -> impl Iterator<Item = U>
     where
         P: IntoIterator<IntoIter = I>,
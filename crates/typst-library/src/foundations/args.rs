@@ -5,7 +5,8 @@ use typst_syntax::{Span, Spanned};
 
 use crate::diag::{bail, error, At, SourceDiagnostic, SourceResult, StrResult};
 use crate::foundations::{
-    cast, func, repr, scope, ty, Array, Dict, FromValue, IntoValue, Repr, Str, Value,
+    cast, func, repr, scope, ty, Array, Dict, FromValue, IntoValue, ParamInfo, Repr, Str,
+    Value,
 };
 
 /// Captured arguments to a function.
@@ -243,19 +244,46 @@ impl Args {
         }
     }
 
-    /// Return an "unexpected argument" error if there is any remaining
-    /// argument.
+    /// Return an "unexpected argument" error for each remaining argument.
     pub fn finish(self) -> SourceResult<()> {
-        if let Some(arg) = self.items.first() {
-            match &arg.name {
-                Some(name) => bail!(arg.span, "unexpected argument: {name}"),
-                _ => bail!(arg.span, "unexpected argument"),
-            }
+        self.finish_with(&[])
+    }
+
+    /// Same as [`finish`](Self::finish), but hints at the full name of a
+    /// parameter when an unexpected named argument abbreviates it.
+    pub fn finish_with(self, params: &[ParamInfo]) -> SourceResult<()> {
+        if self.items.is_empty() {
+            return Ok(());
         }
-        Ok(())
+
+        Err(self
+            .items
+            .iter()
+            .map(|arg| match &arg.name {
+                Some(name) => {
+                    let mut diag = error!(arg.span, "unexpected argument: {name}");
+                    if let Some(param) = abbreviated(params, name) {
+                        diag.hint(eco_format!("did you mean `{}`?", param.name));
+                    }
+                    diag
+                }
+                None => error!(arg.span, "unexpected argument"),
+            })
+            .collect())
     }
 }
 
+/// Finds the only named parameter whose name starts with the given, shorter
+/// name.
+fn abbreviated<'a>(params: &'a [ParamInfo], name: &str) -> Option<&'a ParamInfo> {
+    let mut candidates = params
+        .iter()
+        .filter(|param| param.named && param.name.len() > name.len())
+        .filter(|param| param.name.starts_with(name));
+    let param = candidates.next()?;
+    candidates.next().is_none().then_some(param)
+}
+
 /// A key that can be used to get an argument: either the index of a positional
 /// argument, or the name of a named argument.
 #[derive(Debug, Clone, Eq, PartialEq)]
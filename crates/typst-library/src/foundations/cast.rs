@@ -331,9 +331,15 @@ impl CastInfo {
         let mut msg: HintedString = msg.into();
 
         if let Value::Int(i) = found {
-            if !matching_type && parts.iter().any(|p| p == "length") {
+            if !matching_type
+                && parts.iter().any(|p| p == "length" || p == "relative length")
+            {
                 msg.hint(eco_format!("a length needs a unit - did you mean {i}pt?"));
             }
+            if !matching_type && parts.iter().any(|p| p == "ratio") {
+                let percent = i.saturating_mul(100);
+                msg.hint(eco_format!("a ratio needs a unit - did you mean {percent}%?"));
+            }
         } else if let Value::Str(s) = found {
             if !matching_type && parts.iter().any(|p| p == "label") {
                 if typst_syntax::is_valid_label_literal_id(s) {
@@ -345,6 +351,12 @@ impl CastInfo {
                     msg.hint(eco_format!("use `label({})` to create a label", s.repr()));
                 }
             }
+            if !matching_type
+                && parts.iter().any(|p| p == "direction")
+                && matches!(s.as_str(), "ltr" | "rtl" | "ttb" | "btt")
+            {
+                msg.hint(eco_format!("directions are not strings, try `{s}` instead"));
+            }
         } else if let Value::Decimal(_) = found {
             if !matching_type && parts.iter().any(|p| p == "float") {
                 msg.hint(eco_format!(
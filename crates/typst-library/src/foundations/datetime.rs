@@ -446,6 +446,26 @@ impl Datetime {
             Self::Time(_) => None,
         }
     }
+
+    /// The week of the year according to ISO 8601, or `{none}` for times
+    /// without a date.
+    ///
+    /// ISO weeks start on Monday and the first week of a year is the one that
+    /// contains its first Thursday. Thus, the first days of January can still
+    /// belong to the last week of the previous year.
+    ///
+    /// ```example
+    /// #datetime(year: 2021, month: 1, day: 1).week() \
+    /// #datetime(year: 2021, month: 1, day: 4).week()
+    /// ```
+    #[func]
+    pub fn week(&self) -> Option<u8> {
+        match self {
+            Self::Datetime(datetime) => Some(datetime.iso_week()),
+            Self::Date(date) => Some(date.iso_week()),
+            Self::Time(_) => None,
+        }
+    }
 }
 
 impl Repr for Datetime {
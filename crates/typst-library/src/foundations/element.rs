@@ -62,7 +62,7 @@ impl Element {
     /// Execute the set rule for the element and return the resulting style map.
     pub fn set(self, engine: &mut Engine, mut args: Args) -> SourceResult<Styles> {
         let styles = (self.0.set)(engine, &mut args)?;
-        args.finish()?;
+        args.finish_with(self.params())?;
         Ok(styles)
     }
 
@@ -6,7 +6,7 @@ use crate::diag::{bail, StrResult};
 use crate::foundations::{
     cast, func, repr, scope, ty, Bytes, Decimal, Endianness, Repr, Str,
 };
-use crate::layout::Ratio;
+use crate::layout::{Fr, Ratio};
 
 /// A floating-point number.
 ///
@@ -44,6 +44,7 @@ impl f64 {
     ///   absolute value less than `{calc.pow(2, 53)}`, this conversion is
     ///   exact.
     /// - Ratios are divided by 100%.
+    /// - Fractions are converted to their number of `{1fr}` units.
     /// - Strings are parsed in base 10 to the closest 64-bit float. Exponential
     ///   notation is supported.
     ///
@@ -52,6 +53,7 @@ impl f64 {
     /// #float(true) \
     /// #float(4) \
     /// #float(40%) \
+    /// #float(2.5fr) \
     /// #float("2.7") \
     /// #float("1e5")
     /// ```
@@ -179,6 +181,7 @@ cast! {
     v: i64 => Self(v as f64),
     v: Decimal => Self(f64::try_from(v).map_err(|_| eco_format!("invalid float: {}", v))?),
     v: Ratio => Self(v.get()),
+    v: Fr => Self(v.get()),
     v: Str => Self(
         parse_float(v.clone().into())
             .map_err(|_| eco_format!("invalid float: {}", v))?
@@ -2,6 +2,7 @@
 pub use typst_macros::func;
 
 use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, LazyLock};
 
 use comemo::{Tracked, TrackedMut};
@@ -151,6 +152,8 @@ enum Repr {
     Element(Element),
     /// A user-defined closure.
     Closure(Arc<LazyHash<Closure>>),
+    /// A function provided by the embedding application.
+    Host(Arc<HostFunc>),
     /// A nested function with pre-applied arguments.
     With(Arc<(Func, Args)>),
 }
@@ -164,6 +167,7 @@ impl Func {
             Repr::Native(native) => Some(native.name),
             Repr::Element(elem) => Some(elem.name()),
             Repr::Closure(closure) => closure.name(),
+            Repr::Host(host) => Some(host.name.as_str()),
             Repr::With(with) => with.0.name(),
         }
     }
@@ -175,7 +179,7 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => Some(native.title),
             Repr::Element(elem) => Some(elem.title()),
-            Repr::Closure(_) => None,
+            Repr::Closure(_) | Repr::Host(_) => None,
             Repr::With(with) => with.0.title(),
         }
     }
@@ -185,7 +189,7 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => Some(native.docs),
             Repr::Element(elem) => Some(elem.docs()),
-            Repr::Closure(_) => None,
+            Repr::Closure(_) | Repr::Host(_) => None,
             Repr::With(with) => with.0.docs(),
         }
     }
@@ -203,6 +207,7 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => Some(&native.0.params),
             Repr::Element(elem) => Some(elem.params()),
+            Repr::Host(host) => Some(host.params),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.params(),
         }
@@ -220,7 +225,7 @@ impl Func {
             Repr::Element(_) => {
                 Some(singleton!(CastInfo, CastInfo::Type(Type::of::<Content>())))
             }
-            Repr::Closure(_) => None,
+            Repr::Closure(_) | Repr::Host(_) => None,
             Repr::With(with) => with.0.returns(),
         }
     }
@@ -230,7 +235,7 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => native.keywords,
             Repr::Element(elem) => elem.keywords(),
-            Repr::Closure(_) => &[],
+            Repr::Closure(_) | Repr::Host(_) => &[],
             Repr::With(with) => with.0.keywords(),
         }
     }
@@ -240,7 +245,7 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => Some(&native.0.scope),
             Repr::Element(elem) => Some(elem.scope()),
-            Repr::Closure(_) => None,
+            Repr::Closure(_) | Repr::Host(_) => None,
             Repr::With(with) => with.0.scope(),
         }
     }
@@ -287,14 +292,19 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => {
                 let value = (native.function)(engine, context, &mut args)?;
-                args.finish()?;
+                args.finish_with(&native.0.params)?;
                 Ok(value)
             }
             Repr::Element(func) => {
                 let value = func.construct(engine, &mut args)?;
-                args.finish()?;
+                args.finish_with(func.params())?;
                 Ok(Value::Content(value))
             }
+            Repr::Host(host) => {
+                let value = (host.function)(engine, context, &mut args)?;
+                args.finish()?;
+                Ok(value)
+            }
             Repr::Closure(closure) => (engine.routines.eval_closure)(
                 self,
                 closure,
@@ -528,6 +538,65 @@ impl Closure {
     }
 }
 
+/// A function provided by the application that embeds Typst.
+///
+/// Unlike native functions, host functions can be created at runtime from
+/// closures, for example to give documents access to data of the host
+/// application. Like all functions, they should be pure: Typst caches their
+/// results and may skip calling them again with the same arguments.
+pub struct HostFunc {
+    /// The function's name, as exposed to Typst.
+    name: EcoString,
+    /// Details about the function's parameters.
+    params: &'static [ParamInfo],
+    /// Invokes the function from Typst.
+    function: Box<HostFn>,
+}
+
+/// The signature of a host function's implementation.
+pub type HostFn =
+    dyn Fn(&mut Engine, Tracked<Context>, &mut Args) -> SourceResult<Value> + Send + Sync;
+
+impl Func {
+    /// Create a function that is implemented by the embedding application.
+    ///
+    /// The `params` describe the parameters for documentation and
+    /// autocompletion. The `function` itself takes the arguments it needs
+    /// from the [`Args`] and arguments that are left over are rejected.
+    pub fn host<F>(
+        name: impl Into<EcoString>,
+        params: &'static [ParamInfo],
+        function: F,
+    ) -> Self
+    where
+        F: Fn(&mut Engine, Tracked<Context>, &mut Args) -> SourceResult<Value>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let host = HostFunc {
+            name: name.into(),
+            params,
+            function: Box::new(function),
+        };
+        Repr::Host(Arc::new(host)).into()
+    }
+}
+
+impl PartialEq for HostFunc {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Hash for HostFunc {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The closure itself can't be hashed, so host functions are identified
+        // by their address, just like they are compared.
+        (self as *const Self).hash(state);
+    }
+}
+
 impl From<Closure> for Func {
     fn from(closure: Closure) -> Self {
         Repr::Closure(Arc::new(LazyHash::new(closure))).into()
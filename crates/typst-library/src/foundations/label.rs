@@ -44,6 +44,22 @@ use crate::foundations::{func, scope, ty, Repr};
 ///
 /// Currently, labels can only be attached to elements in markup mode, not in
 /// code mode. This might change in the future.
+///
+/// # Reusing content
+/// Labels identify a single element, so they are not meant for inserting the
+/// same content in multiple places. To reuse content, such as an address or a
+/// disclaimer, bind it to a variable instead. Each insertion is then laid out
+/// independently, just like content that is written out multiple times.
+///
+/// ```example
+/// #let disclaimer = text(gray)[
+///   All information without warranty.
+/// ]
+///
+/// #disclaimer
+/// Some content.
+/// #disclaimer
+/// ```
 #[ty(scope, cast)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Label(PicoStr);
@@ -2,10 +2,10 @@
 
 use std::cmp::Ordering;
 
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use typst_utils::Numeric;
 
-use crate::diag::{bail, HintedStrResult, StrResult};
+use crate::diag::{bail, HintedStrResult, HintedString, StrResult};
 use crate::foundations::{format_str, Datetime, IntoValue, Regex, Repr, Value};
 use crate::layout::{Alignment, Length, Rel};
 use crate::text::TextElem;
@@ -172,7 +172,10 @@ pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
             mismatch!("cannot add {} and {}", a, b);
         }
 
-        (a, b) => mismatch!("cannot add {} and {}", a, b),
+        (a, b) => {
+            let message = eco_format!("cannot add {} and {}", a.ty(), b.ty());
+            return Err(hint_unit(message, &a, &b));
+        }
     })
 }
 
@@ -216,10 +219,36 @@ pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         (Datetime(a), Duration(b)) => Datetime(a - b),
         (Datetime(a), Datetime(b)) => Duration((a - b)?),
 
-        (a, b) => mismatch!("cannot subtract {1} from {0}", a, b),
+        (a, b) => {
+            let message = eco_format!("cannot subtract {} from {}", b.ty(), a.ty());
+            return Err(hint_unit(message, &a, &b));
+        }
     })
 }
 
+/// Creates a type mismatch error that hints at a missing unit if a plain number
+/// is combined with a value that has one.
+fn hint_unit(message: EcoString, a: &Value, b: &Value) -> HintedString {
+    use Value::*;
+    let mut error = HintedString::new(message);
+    let ((number @ (Int(_) | Float(_)), other) | (other, number @ (Int(_) | Float(_)))) =
+        (a, b)
+    else {
+        return error;
+    };
+
+    let unit = match other {
+        Length(_) | Relative(_) => "pt",
+        Angle(_) => "deg",
+        Ratio(_) => "%",
+        Fraction(_) => "fr",
+        _ => return error,
+    };
+
+    error.hint(eco_format!("to give the number a unit, write `{}{unit}`", number.repr()));
+    error
+}
+
 /// Compute the product of two values.
 pub fn mul(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     use Value::*;
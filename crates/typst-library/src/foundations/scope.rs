@@ -12,7 +12,7 @@ use typst_utils::Static;
 
 use crate::diag::{bail, HintedStrResult, HintedString, StrResult};
 use crate::foundations::{
-    Element, Func, IntoValue, Module, NativeElement, NativeFunc, NativeFuncData,
+    repr, Element, Func, IntoValue, Module, NativeElement, NativeFunc, NativeFuncData,
     NativeType, Type, Value,
 };
 use crate::Library;
@@ -58,7 +58,13 @@ impl<'a> Scopes<'a> {
                     None => None,
                 })
             })
-            .ok_or_else(|| unknown_variable(var))
+            .ok_or_else(|| {
+                let mut err = unknown_variable(var);
+                if let Some(base) = self.base {
+                    hint_scoped(&mut err, base, var);
+                }
+                err
+            })
     }
 
     /// Try to access a variable immutably in math.
@@ -122,9 +128,68 @@ fn unknown_variable(var: &str) -> HintedString {
         ));
     }
 
+    if matches!(var, "def" | "define" | "fn" | "func" | "function" | "macro") {
+        res.hint(
+            "to define a function, use a let binding with parameters: `#let name(param) = [..]`",
+        );
+    }
+
+    match var {
+        "rgba" => res
+            .hint("to specify an alpha component, pass it as the last argument to `rgb`"),
+        "hsl" | "hsv" => res
+            .hint(eco_format!("to create a color in this space, use `color.{var}(..)`")),
+        "hsla" | "hsva" => res.hint(eco_format!(
+            "to specify an alpha component, pass it as the last argument to `color.{}`",
+            &var[..3]
+        )),
+        "lighten" | "darken" | "saturate" | "desaturate" | "negate" => {
+            res.hint(eco_format!(
+                "`{var}` is a method of colors, try calling it on one: `red.{var}(..)`"
+            ))
+        }
+        "mix" => res.hint("to mix colors, use `color.mix(..)`"),
+        "linear" | "radial" | "conic" => {
+            res.hint(eco_format!("to create a gradient, use `gradient.{var}(..)`"))
+        }
+        "justify" => res.hint("to justify text, use `set par(justify: true)`"),
+        _ => {}
+    }
+
     res
 }
 
+/// Adds a hint for definitions with the given name that are nested in the
+/// scope of a global module or element function, like `list.item`.
+#[cold]
+fn hint_scoped(err: &mut HintedString, base: &Library, var: &str) {
+    let is_elem =
+        |value: &Value| matches!(value, Value::Func(func) if func.element().is_some());
+
+    let candidates: Vec<_> = base
+        .global
+        .scope()
+        .iter()
+        .filter(|(_, value, _)| match value {
+            Value::Func(func) if func.element().is_some() => {
+                func.scope().and_then(|scope| scope.get(var)).is_some_and(is_elem)
+            }
+            Value::Module(module) => {
+                matches!(module.scope().get(var), Some(Value::Func(_)))
+            }
+            _ => false,
+        })
+        .map(|(name, _, _)| eco_format!("`{name}.{var}`"))
+        .collect();
+
+    if !candidates.is_empty() {
+        err.hint(eco_format!(
+            "did you mean {}?",
+            repr::separated_list(&candidates, "or")
+        ));
+    }
+}
+
 #[cold]
 fn unknown_variable_math(var: &str, in_global: bool) -> HintedString {
     let mut res = HintedString::new(eco_format!("unknown variable: {}", var));
@@ -280,10 +345,48 @@ impl Scope {
         self.map.get(var)?.category
     }
 
+    /// Bind all definitions of another scope, replacing existing bindings with
+    /// the same names in place.
+    pub fn extend(&mut self, other: Scope) {
+        self.map.extend(other.map);
+    }
+
     /// Iterate over all definitions.
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Value, Span)> {
         self.map.iter().map(|(k, v)| (k, v.read(), v.span))
     }
+
+    /// Iterate over the definitions that were added or overridden since the
+    /// given snapshot of this scope was taken.
+    ///
+    /// A snapshot is simply a clone of the scope. An interactive frontend can
+    /// take one before evaluating new input to report the new definitions
+    /// afterwards and to roll back to it by restoring the clone.
+    pub fn diff<'a>(
+        &'a self,
+        snapshot: &'a Scope,
+    ) -> impl Iterator<Item = (&'a EcoString, &'a Value, ScopeChange)> {
+        self.map.iter().filter_map(|(k, v)| {
+            let change = match snapshot.map.get(k) {
+                None => ScopeChange::Added,
+                Some(old) if old.span != v.span || old.read() != v.read() => {
+                    ScopeChange::Overridden
+                }
+                Some(_) => return None,
+            };
+            Some((k, v.read(), change))
+        })
+    }
+}
+
+/// How a definition changed between two snapshots of a scope.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ScopeChange {
+    /// The name was not defined before.
+    Added,
+    /// The name was defined before, but is now bound to a different value or
+    /// was redefined.
+    Overridden,
 }
 
 impl Debug for Scope {
@@ -414,3 +517,43 @@ pub struct CategoryData {
     pub title: &'static str,
     pub docs: &'static str,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_diff() {
+        let mut scope = Scope::new();
+        scope.define("a", 1);
+        scope.define("b", 2);
+
+        let snapshot = scope.clone();
+        scope.define("b", 3);
+        scope.define("c", 4);
+        scope.define("a", 1);
+
+        let diff: Vec<_> = scope
+            .diff(&snapshot)
+            .map(|(k, _, change)| (k.as_str(), change))
+            .collect();
+        assert_eq!(diff, [("b", ScopeChange::Overridden), ("c", ScopeChange::Added)]);
+        assert_eq!(snapshot.get("b"), Some(&Value::Int(2)));
+    }
+
+    #[test]
+    fn test_scope_extend() {
+        let mut scope = Scope::new();
+        scope.define("a", 1);
+        scope.define("b", 2);
+
+        let mut other = Scope::new();
+        other.define("c", 3);
+        other.define("a", 4);
+        scope.extend(other);
+
+        let names: Vec<_> = scope.iter().map(|(k, _, _)| k.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert_eq!(scope.get("a"), Some(&Value::Int(4)));
+    }
+}
@@ -105,12 +105,16 @@ impl Selector {
     }
 
     /// Define a regex selector.
-    pub fn regex(regex: Regex) -> StrResult<Self> {
+    pub fn regex(regex: Regex) -> HintedStrResult<Self> {
         if regex.as_str().is_empty() {
             bail!("regex selector is empty");
         }
         if regex.is_match("") {
-            bail!("regex matches empty text");
+            bail!(
+                "regex matches empty text";
+                hint: "make sure that the regex matches at least one character, \
+                       for example by using `+` instead of `*`"
+            );
         }
         Ok(Self::Regex(regex))
     }
@@ -595,6 +595,43 @@ impl Str {
         }
     }
 
+    /// Repeats the string a number of times, optionally with a separator in
+    /// between the repetitions.
+    ///
+    /// Without a separator, this is equivalent to multiplying the string by
+    /// the count.
+    ///
+    /// ```example
+    /// #"=".repeat(8) \
+    /// #"ha".repeat(3, separator: "-")
+    /// ```
+    #[func]
+    pub fn repeat_(
+        &self,
+        /// How often to repeat the string.
+        count: usize,
+        /// A string to insert in between the repetitions.
+        #[named]
+        separator: Option<Str>,
+    ) -> StrResult<Str> {
+        let Some(separator) = separator else { return self.repeat(count) };
+        let Some(capacity) = (self.0.len())
+            .checked_add(separator.len())
+            .and_then(|len| len.checked_mul(count))
+        else {
+            bail!("cannot repeat this string {count} times");
+        };
+
+        let mut s = EcoString::with_capacity(capacity);
+        for i in 0..count {
+            if i > 0 {
+                s.push_str(&separator);
+            }
+            s.push_str(self);
+        }
+        Ok(s.into())
+    }
+
     /// Reverse the string.
     #[func(title = "Reverse")]
     pub fn rev(&self) -> Str {
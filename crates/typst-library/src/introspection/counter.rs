@@ -143,6 +143,10 @@ use crate::World;
 /// #context mine.display()
 /// ```
 ///
+/// Note that a string key always creates a custom counter, even if it matches
+/// the name of an element. For example, `{counter("heading")}` is unrelated to
+/// the heading numbers, which are tracked by `{counter(heading)}`.
+///
 /// # How to step
 /// When you define and use a custom counter, in general, you should first step
 /// the counter and then display it. This way, the stepping behaviour of a
@@ -4,12 +4,12 @@ use std::hash::Hash;
 use std::num::NonZeroUsize;
 use std::sync::RwLock;
 
-use ecow::EcoVec;
+use ecow::{eco_format, EcoVec};
 use smallvec::SmallVec;
 use typst_utils::NonZeroExt;
 
-use crate::diag::{bail, StrResult};
-use crate::foundations::{Content, Label, Repr, Selector};
+use crate::diag::{bail, HintedStrResult};
+use crate::foundations::{repr, Content, Label, Repr, Selector};
 use crate::introspection::{Location, Tag};
 use crate::layout::{Frame, FrameItem, Page, Point, Position, Transform};
 use crate::model::Numbering;
@@ -200,7 +200,7 @@ impl Introspector {
     }
 
     /// Query for the first element that matches the selector.
-    pub fn query_unique(&self, selector: &Selector) -> StrResult<Content> {
+    pub fn query_unique(&self, selector: &Selector) -> HintedStrResult<Content> {
         match selector {
             Selector::Location(location) => self
                 .get_by_loc(location)
@@ -221,11 +221,28 @@ impl Introspector {
     }
 
     /// Query for a unique element with the label.
-    pub fn query_label(&self, label: Label) -> StrResult<&Content> {
+    pub fn query_label(&self, label: Label) -> HintedStrResult<&Content> {
         match *self.labels.get(&label) {
             [idx] => Ok(self.get_by_idx(idx)),
             [] => bail!("label `{}` does not exist in the document", label.repr()),
-            _ => bail!("label `{}` occurs multiple times in the document", label.repr()),
+            ref indices => {
+                let pages: Vec<_> = indices
+                    .iter()
+                    .filter_map(|&idx| self.get_by_idx(idx).location())
+                    .map(|loc| self.page(loc))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .map(|page| eco_format!("{page}"))
+                    .collect();
+                bail!(
+                    "label `{}` occurs multiple times in the document", label.repr();
+                    hint: "it is attached to {} elements on {} {}",
+                        indices.len(),
+                        if pages.len() == 1 { "page" } else { "pages" },
+                        repr::separated_list(&pages, "and");
+                    hint: "use a unique label for each element you want to refer to"
+                )
+            }
         }
     }
 
@@ -1,8 +1,9 @@
 use std::ops::Add;
 
 use ecow::{eco_format, EcoString};
+use typst_syntax::Spanned;
 
-use crate::diag::{bail, HintedStrResult, SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, func, scope, ty, CastInfo, Content, Fold, FromValue, IntoValue, Packed,
@@ -87,9 +88,20 @@ pub struct AlignElem {
     ///   rect(inset: 12pt)[ركن]
     /// )
     /// ```
+    ///
+    /// Instead of combining them with `+`, a horizontal and a vertical
+    /// alignment can also be passed as two separate arguments, as in
+    /// `{align(right, bottom)[..]}`.
     #[positional]
     #[fold]
     #[default]
+    #[parse(
+        let first = args.find::<Alignment>()?;
+        match (first, args.find::<Spanned<Alignment>>()?) {
+            (Some(first), Some(second)) => Some((first + second.v).at(second.span)?),
+            (first, _) => first,
+        }
+    )]
     pub alignment: Alignment,
 
     /// The content to align.
@@ -115,6 +115,20 @@ impl Angle {
     pub fn to_deg(self) -> f64 {
         self.to_unit(AngleUnit::Deg)
     }
+
+    /// Normalizes this angle to the range from `{0deg}` (inclusive) to
+    /// `{360deg}` (exclusive), while keeping the direction it describes.
+    ///
+    /// ```example
+    /// #(-90deg).normalize() \
+    /// #(450deg).normalize()
+    /// ```
+    #[func]
+    pub fn normalize(self) -> Angle {
+        // For tiny negative angles, the remainder rounds up to a full turn.
+        let raw = self.to_raw().rem_euclid(2.0 * PI);
+        Self::raw(if raw < 2.0 * PI { raw } else { 0.0 })
+    }
 }
 
 impl Numeric for Angle {
@@ -8,10 +8,10 @@ use crate::layout::{BlockElem, Length, Ratio, Rel};
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function lets you separate the interior of any container into
-/// multiple columns. It will currently not balance the height of the columns.
-/// Instead, the columns will take up the height of their container or the
-/// remaining height on the page. Support for balanced columns is planned for
-/// the future.
+/// multiple columns. By default, it does not balance the height of the
+/// columns. Instead, the columns will take up the height of their container or
+/// the remaining height on the page. To distribute the content evenly, enable
+/// the [`balance`]($columns.balance) parameter.
 ///
 /// # Page-level columns { #page-level }
 /// If you need to insert columns across your whole document, use the `{page}`
@@ -53,6 +53,22 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the height of the columns.
+    ///
+    /// If enabled, the columns are made just tall enough to hold the content,
+    /// so that it is distributed evenly across them. This only applies if the
+    /// content fits into the remaining space of the container or page and the
+    /// container doesn't have a fixed height.
+    ///
+    /// ```example
+    /// #block(
+    ///   fill: aqua.lighten(60%),
+    ///   columns(2, balance: true, lorem(20)),
+    /// )
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -27,6 +27,19 @@ use crate::visualize::{Paint, Stroke};
 /// )
 /// for more information.
 /// ```
+///
+/// Like a [rectangle]($rect), a box can have a background fill, a border, and
+/// rounded corners. Unlike a rectangle, it has no padding by default.
+///
+/// ```example
+/// Press #box(
+///   fill: luma(235),
+///   stroke: 0.5pt + luma(150),
+///   radius: 2pt,
+///   inset: (x: 3pt),
+///   outset: (y: 3pt),
+/// )[Enter] to confirm.
+/// ```
 #[elem]
 pub struct BoxElem {
     /// The width of the box.
@@ -76,7 +89,7 @@ pub struct BoxElem {
     /// current [text edges]($text.top-edge).
     ///
     /// ```example
-    /// #rect(inset: 0pt)[Tight]
+    /// A #box(fill: aqua, inset: 2pt)[padded] box.
     /// ```
     #[resolve]
     #[fold]
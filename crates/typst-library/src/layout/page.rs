@@ -10,7 +10,7 @@ use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, Args, AutoValue, Cast, Construct, Content, Context, Dict, Fold, Func,
-    NativeElement, Set, Smart, StyleChain, Value,
+    NativeElement, Repr, Set, Smart, Str, StyleChain, Value,
 };
 use crate::layout::{
     Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
@@ -44,7 +44,8 @@ pub struct PageElem {
     /// A standard paper size to set width and height.
     ///
     /// This is just a shorthand for setting `width` and `height` and, as such,
-    /// cannot be retrieved in a context expression.
+    /// cannot be retrieved in a context expression. Paper names are not case
+    /// sensitive, so `{"A4"}` works just like `{"a4"}`.
     #[external]
     #[default(Paper::A4)]
     pub paper: Paper,
@@ -276,6 +277,23 @@ pub struct PageElem {
     ///
     /// #lorem(19)
     /// ```
+    ///
+    /// The header is realized separately for each page. Within a [context]
+    /// expression, it can thus depend on the page it ends up on, for example
+    /// to show the current page number in a running header.
+    ///
+    /// ```example
+    /// #set page(
+    ///   margin: (top: 32pt, bottom: 20pt),
+    ///   header: context [
+    ///     _Exercise Sheet 3_
+    ///     #h(1fr)
+    ///     Page #counter(page).display()
+    ///   ],
+    /// )
+    ///
+    /// #lorem(19)
+    /// ```
     #[borrowed]
     #[ghost]
     pub header: Smart<Option<Content>>,
@@ -424,6 +442,17 @@ pub struct PagebreakElem {
     /// #pagebreak(to: "odd")
     /// Third.
     /// ```
+    ///
+    /// In books, chapters typically start on a right-hand page, which is an odd
+    /// page in a left-to-right document. Making the page break weak avoids an
+    /// additional empty page if the heading already is at the top of a page.
+    ///
+    /// ```typ
+    /// #show heading.where(level: 1): it => {
+    ///   pagebreak(weak: true, to: "odd")
+    ///   it
+    /// }
+    /// ```
     pub to: Option<Parity>,
 
     /// Whether this pagebreak designates an end boundary of a page run. This is
@@ -784,6 +813,19 @@ macro_rules! papers {
                 /// Produces a paper of the respective size.
                 $name => Self::$var,
             )*
+            v: Str => match v.parse() {
+                Ok(paper) => paper,
+                Err(_) => match Paper::from_str(&format!("us-{v}")) {
+                    Ok(paper) => bail!(
+                        "unknown paper size: {}", v.repr();
+                        hint: "did you mean \"{}\"?", paper.name
+                    ),
+                    Err(_) => bail!(
+                        "unknown paper size: {}", v.repr();
+                        hint: "paper sizes are named like \"a4\" or \"us-letter\""
+                    ),
+                },
+            },
         }
     };
 }
@@ -66,6 +66,26 @@ use crate::layout::{Alignment, Em, Length, Rel};
 ///
 /// The zero-width weak spacing serves to discard spaces between the function
 /// call and the next word.
+///
+/// # Margin notes
+/// Since the offsets may move content beyond the bounds of its container,
+/// overlaid placement can also be used to put notes into the page margin. If
+/// the alignment has no vertical component, the note stays at the vertical
+/// position of the `place` call in the flow.
+///
+/// ```example
+/// >>> #set page(height: 80pt)
+/// #set page(margin: (left: 60pt))
+/// #let note(body) = place(
+///   left,
+///   dx: -55pt,
+///   text(7pt, body),
+/// )
+///
+/// #note[Revised]
+/// This paragraph was changed
+/// in the latest revision.
+/// ```
 #[elem(scope, Locatable, Unqueriable)]
 pub struct PlaceElem {
     /// Relative to which position in the parent container to place the content.
@@ -24,6 +24,24 @@ use crate::layout::{BlockElem, Length};
 ///   Berlin, the 22nd of December, 2022
 /// ]
 /// ```
+///
+/// # Leaders and tab stops
+/// Within a paragraph, a repeat in a fractionally sized box fills the space
+/// between two pieces of text with a leader, as in a table of contents. To
+/// align text at fixed positions like tab stops, put the preceding text into a
+/// box of fixed width instead.
+///
+/// ```example
+/// #let entry(title, page) = [
+///   #title #box(width: 1fr, repeat[.]) #page
+/// ]
+///
+/// #entry[Introduction][1] \
+/// #entry[Related work][4]
+///
+/// #box(width: 2.5cm)[Name:] Jane Doe \
+/// #box(width: 2.5cm)[Affiliation:] ACME
+/// ```
 #[elem(Show)]
 pub struct RepeatElem {
     /// The content to repeat.
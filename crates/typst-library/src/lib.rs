@@ -172,6 +172,7 @@ impl Default for Library {
 pub struct LibraryBuilder {
     inputs: Option<Dict>,
     features: Features,
+    definitions: Option<Scope>,
 }
 
 impl LibraryBuilder {
@@ -189,12 +190,27 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure additional global definitions, which replace built-in ones
+    /// with the same names.
+    ///
+    /// This way, an embedder can provide definitions written in Typst itself,
+    /// for example by evaluating bundled source files into a module and
+    /// passing its scope. The `std` module keeps the original built-in
+    /// definitions, so that replacements can build upon them.
+    pub fn with_definitions(mut self, definitions: Scope) -> Self {
+        self.definitions = Some(definitions);
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
         let inputs = self.inputs.unwrap_or_default();
-        let global = global(math.clone(), inputs);
+        let mut global = global(math.clone(), inputs);
         let std = Value::Module(global.clone());
+        if let Some(definitions) = self.definitions {
+            global.scope_mut().extend(definitions);
+        }
         Library {
             global,
             math,
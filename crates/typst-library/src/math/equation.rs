@@ -38,6 +38,18 @@ use crate::text::{FontFamily, FontList, FontWeight, LocalName, TextElem};
 /// By default, block-level equations will not break across pages. This can be
 /// changed through `{show math.equation: set block(breakable: true)}`.
 ///
+/// # Line breaks
+/// Inline equations break automatically after relations and binary operators
+/// when they don't fit into the line. Block-level equations only break where
+/// you insert a line break with a backslash. To align the resulting lines,
+/// for example at their relation operators, mark the alignment points with an
+/// ampersand.
+///
+/// ```example
+/// $ f(x) &= (x + 1)^2 \
+///        &= x^2 + 2x + 1 $
+/// ```
+///
 /// # Syntax
 /// This function also has dedicated syntax: Write mathematical markup within
 /// dollar signs to create an equation. Starting and ending the equation with at
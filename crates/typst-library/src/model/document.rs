@@ -7,7 +7,7 @@ use crate::foundations::{
     Styles, Value,
 };
 use crate::introspection::Introspector;
-use crate::layout::Page;
+use crate::layout::{Abs, Frame, FrameItem, Page, Transform};
 
 /// The root element of a document and its metadata.
 ///
@@ -39,6 +39,13 @@ pub struct DocumentElem {
     #[ghost]
     pub author: Author,
 
+    /// The document's description.
+    ///
+    /// PDF viewers typically show it as the document's subject. Just like the
+    /// title, it is converted to plain text.
+    #[ghost]
+    pub description: Option<Content>,
+
     /// The document's keywords.
     #[ghost]
     pub keywords: Keywords,
@@ -97,6 +104,54 @@ pub struct Document {
     pub introspector: Introspector,
 }
 
+impl Document {
+    /// Extracts the plain text of the laid-out pages.
+    ///
+    /// The text is collected in the order in which it appears in the page
+    /// frames, which usually matches the reading order. A line break is
+    /// inserted whenever the baseline changes and pages are separated by form
+    /// feeds. This is useful for search indexing, diffing, and word counts.
+    pub fn text(&self) -> EcoString {
+        let mut text = EcoString::new();
+        for (i, page) in self.pages.iter().enumerate() {
+            if i > 0 {
+                text.push('\u{c}');
+            }
+            let mut baseline = None;
+            frame_text(&mut text, &mut baseline, &page.frame, Transform::identity());
+        }
+        text
+    }
+}
+
+/// Appends the text in a frame, with line breaks between different baselines.
+fn frame_text(
+    text: &mut EcoString,
+    baseline: &mut Option<Abs>,
+    frame: &Frame,
+    ts: Transform,
+) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                frame_text(text, baseline, &group.frame, ts);
+            }
+            FrameItem::Text(item) => {
+                let y = pos.transform(ts).y;
+                if baseline.is_some_and(|prev: Abs| !prev.approx_eq(y)) {
+                    text.push('\n');
+                }
+                *baseline = Some(y);
+                text.push_str(&item.text);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Details about the document.
 #[derive(Debug, Default, Clone, PartialEq, Hash)]
 pub struct DocumentInfo {
@@ -104,6 +159,8 @@ pub struct DocumentInfo {
     pub title: Option<EcoString>,
     /// The document's author.
     pub author: Vec<EcoString>,
+    /// The document's description.
+    pub description: Option<EcoString>,
     /// The document's keywords.
     pub keywords: Vec<EcoString>,
     /// The document's creation date.
@@ -124,6 +181,10 @@ impl DocumentInfo {
         if has(<DocumentElem as Fields>::Enum::Author) {
             self.author = DocumentElem::author_in(chain).0;
         }
+        if has(<DocumentElem as Fields>::Enum::Description) {
+            self.description =
+                DocumentElem::description_in(chain).map(|content| content.plain_text());
+        }
         if has(<DocumentElem as Fields>::Enum::Keywords) {
             self.keywords = DocumentElem::keywords_in(chain).0;
         }
@@ -136,10 +197,80 @@ impl DocumentInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::foundations::Bytes;
+    use crate::layout::{GroupItem, Point, Ratio, Size};
+    use crate::text::{Font, Lang, TextItem};
+    use crate::visualize::Color;
+
+    /// A frame with text runs at the given positions.
+    fn frame(runs: &[(f64, f64, &str)]) -> Frame {
+        let data = typst_dev_assets::fonts().next().unwrap();
+        let font = Font::new(Bytes::from_static(data), 0).unwrap();
+        let mut frame = Frame::soft(Size::new(Abs::pt(100.0), Abs::pt(100.0)));
+        for &(x, y, text) in runs {
+            let item = TextItem {
+                font: font.clone(),
+                size: Abs::pt(10.0),
+                fill: Color::BLACK.into(),
+                stroke: None,
+                lang: Lang::ENGLISH,
+                region: None,
+                text: text.into(),
+                glyphs: vec![],
+            };
+            frame.push(Point::new(Abs::pt(x), Abs::pt(y)), FrameItem::Text(item));
+        }
+        frame
+    }
+
+    /// A document with one page per frame.
+    fn document(frames: Vec<Frame>) -> Document {
+        let pages = frames
+            .into_iter()
+            .map(|frame| Page {
+                frame,
+                fill: Smart::Auto,
+                numbering: None,
+                supplement: Content::empty(),
+                number: 1,
+            })
+            .collect();
+        Document { pages, ..Default::default() }
+    }
 
     #[test]
     fn test_document_is_send_and_sync() {
         fn ensure_send_and_sync<T: Send + Sync>() {}
         ensure_send_and_sync::<Document>();
     }
+
+    #[test]
+    fn test_document_text_lines() {
+        let frame =
+            frame(&[(0.0, 10.0, "Hello"), (30.0, 10.0, " world"), (0.0, 22.0, "Next")]);
+        assert_eq!(document(vec![frame]).text(), "Hello world\nNext");
+    }
+
+    #[test]
+    fn test_document_text_pages() {
+        let pages = vec![frame(&[(0.0, 10.0, "One")]), frame(&[(0.0, 10.0, "Two")])];
+        assert_eq!(document(pages).text(), "One\u{c}Two");
+        assert_eq!(document(vec![]).text(), "");
+    }
+
+    #[test]
+    fn test_document_text_groups() {
+        // The group's transform moves its text onto the outer baseline.
+        let mut group = GroupItem::new(frame(&[(0.0, 0.0, "b")]));
+        group.transform = Transform::translate(Abs::zero(), Abs::pt(5.0));
+        let mut outer = frame(&[(0.0, 10.0, "a")]);
+        outer.push(Point::new(Abs::pt(10.0), Abs::pt(5.0)), FrameItem::Group(group));
+
+        // A scaled group ends up on a different baseline.
+        let mut scaled = GroupItem::new(frame(&[(0.0, 10.0, "c")]));
+        scaled.transform = Transform::scale(Ratio::new(2.0), Ratio::new(2.0));
+        outer.push(Point::zero(), FrameItem::Group(scaled));
+
+        assert_eq!(document(vec![outer]).text(), "ab\nc");
+    }
 }
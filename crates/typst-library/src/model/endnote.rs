@@ -0,0 +1,150 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use typst_utils::NonZeroExt;
+
+use crate::diag::{warning, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, LocatableSelector, NativeElement, Packed, Show, StyleChain,
+};
+use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
+use crate::layout::{Em, HElem};
+use crate::model::{Destination, Numbering, NumberingPattern, ParbreakElem};
+use crate::text::SuperElem;
+
+/// An endnote.
+///
+/// Like a [footnote], an endnote inserts a superscript number into the running
+/// text. Instead of at the bottom of the page, its note is listed wherever
+/// [`endnotes`] is called, for example at the end of a chapter or of the whole
+/// document.
+///
+/// # Example
+/// ```example
+/// Typst is a new markup-based
+/// typesetting system.#endnote[It was
+/// started in 2019.]
+///
+/// = Notes
+/// #endnotes()
+/// ```
+#[elem(Locatable, Show, Count)]
+pub struct EndnoteElem {
+    /// How to number endnotes.
+    ///
+    /// Endnotes are numbered sequentially throughout the document, even if
+    /// they are listed in multiple places.
+    ///
+    /// ```example
+    /// #set endnote(numbering: "i")
+    ///
+    /// First#endnote[One] and
+    /// second#endnote[Two].
+    ///
+    /// #endnotes()
+    /// ```
+    #[borrowed]
+    #[default(Numbering::Pattern(NumberingPattern::from_str("1").unwrap()))]
+    pub numbering: Numbering,
+
+    /// The content to put into the endnote.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<EndnoteElem> {
+    #[typst_macros::time(name = "endnote", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let loc = self.location().unwrap();
+        let numbering = self.numbering(styles);
+        let counter = Counter::of(EndnoteElem::elem());
+        let num = counter.display_at_loc(engine, loc, styles, numbering)?;
+        let sup = SuperElem::new(num).pack().spanned(span);
+
+        // The note is only linked if a listing after it contains the note.
+        let listing = EndnotesElem::elem()
+            .select()
+            .after(LocatableSelector(loc.into()), false);
+        let sup = if engine.introspector.query_first(&listing).is_some() {
+            sup.linked(Destination::Location(loc.variant(1)))
+        } else {
+            engine.sink.warn(warning!(
+                span, "endnote is never listed";
+                hint: "call `endnotes()` after the endnote to list it"
+            ));
+            sup
+        };
+
+        // Add zero-width weak spacing to make the endnote "sticky".
+        Ok(HElem::hole().pack() + sup)
+    }
+}
+
+impl Count for Packed<EndnoteElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        Some(CounterUpdate::Step(NonZeroUsize::ONE))
+    }
+}
+
+/// A listing of endnotes.
+///
+/// Lists all endnotes since the previous listing, or since the start of the
+/// document if there is none. This way, calling this function at the end of
+/// each chapter produces per-chapter notes.
+///
+/// ```example
+/// = Chapter 1
+/// Some claim.#endnote[A source.]
+/// #endnotes()
+///
+/// = Chapter 2
+/// Another claim.#endnote[Another source.]
+/// #endnotes()
+/// ```
+#[elem(name = "endnotes", title = "Endnote Listing", Locatable, Show)]
+pub struct EndnotesElem {}
+
+impl Show for Packed<EndnotesElem> {
+    #[typst_macros::time(name = "endnotes", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let span = self.span();
+        let loc = self.location().unwrap();
+
+        let mut selector = EndnoteElem::elem()
+            .select()
+            .before(LocatableSelector(loc.into()), false);
+        let previous = engine.introspector.query(
+            &EndnotesElem::elem()
+                .select()
+                .before(LocatableSelector(loc.into()), false),
+        );
+        if let Some(start) = previous.last().and_then(Content::location) {
+            selector = selector.after(LocatableSelector(start.into()), false);
+        }
+
+        let number_gap = Em::new(0.05);
+        let default = StyleChain::default();
+        let counter = Counter::of(EndnoteElem::elem());
+        let mut seq = vec![];
+        for elem in engine.introspector.query(&selector).iter() {
+            let note = elem.to_packed::<EndnoteElem>().unwrap();
+            let note_loc = note.location().unwrap();
+            let numbering = note.numbering(default);
+            let num = counter.display_at_loc(engine, note_loc, styles, numbering)?;
+            let sup = SuperElem::new(num)
+                .pack()
+                .spanned(span)
+                .linked(Destination::Location(note_loc))
+                .located(note_loc.variant(1));
+
+            seq.push(ParbreakElem::shared().clone());
+            seq.push(sup);
+            seq.push(HElem::new(number_gap.into()).with_weak(true).pack());
+            seq.push(note.body().clone());
+        }
+
+        Ok(Content::sequence(seq))
+    }
+}
@@ -2,11 +2,10 @@ use std::str::FromStr;
 
 use smallvec::SmallVec;
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Array, Content, NativeElement, Packed, Show, Smart, StyleChain,
-    Styles,
+    cast, elem, scope, Content, NativeElement, Packed, Show, Smart, StyleChain, Styles,
 };
 use crate::layout::{Alignment, BlockElem, Em, HAlignment, Length, VAlignment, VElem};
 use crate::model::{ListItemLike, ListLike, Numbering, NumberingPattern, ParElem};
@@ -244,14 +243,7 @@ pub struct EnumItem {
 
 cast! {
     EnumItem,
-    array: Array => {
-        let mut iter = array.into_iter();
-        let (number, body) = match (iter.next(), iter.next(), iter.next()) {
-            (Some(a), Some(b), None) => (a.cast()?, b.cast()?),
-            _ => bail!("array must contain exactly two entries"),
-        };
-        Self::new(body).with_number(number)
-    },
+    (number, body): (Option<usize>, Content) => Self::new(body).with_number(number),
     v: Content => v.unpack::<Self>().unwrap_or_else(Self::new),
 }
 
@@ -157,6 +157,20 @@ pub struct FigureElem {
     /// The figure's caption.
     pub caption: Option<Packed<FigureCaption>>,
 
+    /// A text describing the figure's contents.
+    ///
+    /// It is used as the alternative description of the images in the
+    /// figure's body that don't have an [`alt`]($image.alt) text of their own.
+    ///
+    /// ```example
+    /// #figure(
+    ///   image("glacier.jpg", width: 60%),
+    ///   alt: "A glacier between two mountains",
+    ///   caption: [A glacier],
+    /// )
+    /// ```
+    pub alt: Option<EcoString>,
+
     /// The kind of figure this is.
     ///
     /// All figures of the same kind share a common counter.
@@ -277,7 +291,11 @@ impl Synthesize for Packed<FigureElem> {
                 };
 
                 if numbering.is_some() && name.is_none() {
-                    bail!(span, "please specify the figure's supplement")
+                    bail!(
+                        span, "please specify the figure's supplement";
+                        hint: "custom figure kinds have no default supplement";
+                        hint: "alternatively, set `numbering: none` to disable numbering"
+                    )
                 }
 
                 Some(name.unwrap_or_default())
@@ -328,6 +346,11 @@ impl Show for Packed<FigureElem> {
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let mut realized = self.body().clone();
 
+        // Describe the images in the body.
+        if let Some(alt) = self.alt(styles) {
+            realized = realized.styled(ImageElem::set_alt(Some(alt)));
+        }
+
         // Build the caption, if any.
         if let Some(caption) = self.caption(styles) {
             let v = VElem::new(self.gap(styles).into()).with_weak(true).pack();
@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 use typst_utils::NonZeroExt;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, scope, Content, Label, NativeElement, Packed, Show, ShowSet, Smart,
@@ -119,7 +119,7 @@ impl FootnoteElem {
 
 impl Packed<FootnoteElem> {
     /// Returns the location of the definition of this footnote.
-    pub fn declaration_location(&self, engine: &Engine) -> StrResult<Location> {
+    pub fn declaration_location(&self, engine: &Engine) -> HintedStrResult<Location> {
         match self.body() {
             FootnoteBody::Reference(label) => {
                 let element = engine.introspector.query_label(*label)?;
@@ -237,6 +237,21 @@ pub struct FootnoteEntry {
     )]
     pub separator: Content,
 
+    /// The separator above a footnote listing that starts with the rest of a
+    /// footnote that didn't fit onto the previous page. If `{auto}`, the
+    /// regular `separator` is used.
+    ///
+    /// ```example
+    /// #set page(height: 100pt)
+    /// #set footnote.entry(
+    ///   continuation-separator: line(length: 100%, stroke: 0.5pt)
+    /// )
+    ///
+    /// A long note.
+    /// #footnote(lorem(30))
+    /// ```
+    pub continuation_separator: Smart<Content>,
+
     /// The amount of clearance between the document body and the separator.
     ///
     /// ```example
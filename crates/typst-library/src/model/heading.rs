@@ -153,6 +153,10 @@ pub struct HeadingElem {
     /// Setting this property to either `{true}` (bookmark) or `{false}` (don't
     /// bookmark) bypasses that behavior.
     ///
+    /// Bookmarks are nested according to the heading levels and link to the
+    /// position of the heading on its page. Their titles consist of the plain
+    /// text of the heading's body, without its numbering or formatting.
+    ///
     /// ```example
     /// #heading[Normal heading]
     /// This heading will be shown in
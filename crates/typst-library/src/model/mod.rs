@@ -4,6 +4,7 @@ mod bibliography;
 mod cite;
 mod document;
 mod emph;
+mod endnote;
 #[path = "enum.rs"]
 mod enum_;
 mod figure;
@@ -17,6 +18,7 @@ mod outline;
 mod par;
 mod quote;
 mod reference;
+mod signature;
 mod strong;
 mod table;
 mod terms;
@@ -25,6 +27,7 @@ pub use self::bibliography::*;
 pub use self::cite::*;
 pub use self::document::*;
 pub use self::emph::*;
+pub use self::endnote::*;
 pub use self::enum_::*;
 pub use self::figure::*;
 pub use self::footnote::*;
@@ -36,6 +39,7 @@ pub use self::outline::*;
 pub use self::par::*;
 pub use self::quote::*;
 pub use self::reference::*;
+pub use self::signature::*;
 pub use self::strong::*;
 pub use self::table::*;
 pub use self::terms::*;
@@ -60,6 +64,8 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<HeadingElem>();
     global.define_elem::<FigureElem>();
     global.define_elem::<FootnoteElem>();
+    global.define_elem::<EndnoteElem>();
+    global.define_elem::<EndnotesElem>();
     global.define_elem::<QuoteElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
@@ -71,5 +77,6 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<TermsElem>();
     global.define_elem::<EmphElem>();
     global.define_elem::<StrongElem>();
+    global.define_elem::<SignatureElem>();
     global.define_func::<numbering>();
 }
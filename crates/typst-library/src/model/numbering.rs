@@ -6,9 +6,9 @@ use chinese_number::{
 use comemo::Tracked;
 use ecow::{eco_format, EcoString, EcoVec};
 
-use crate::diag::SourceResult;
+use crate::diag::{bail, HintedStrResult, SourceResult};
 use crate::engine::Engine;
-use crate::foundations::{cast, func, Context, Func, Str, Value};
+use crate::foundations::{cast, func, Array, Context, Func, IntoValue, Str, Value};
 use crate::text::Case;
 
 /// Applies a numbering to a sequence of numbers.
@@ -80,6 +80,17 @@ pub fn numbering(
     /// particularly useful in itself, it means that you can just give arbitrary
     /// numberings to the `numbering` function without caring whether they are
     /// defined as a pattern or function.
+    ///
+    /// Finally, this parameter can be an array of strings. These symbols are
+    /// cycled through like the `*` counting symbol: Once all of them are used
+    /// up, they are repeated twice, then three times, and so on. Only the last
+    /// of the given numbers is displayed.
+    ///
+    /// ```example
+    /// #let suits = ("♠", "♥", "♦", "♣")
+    /// #numbering(suits, 2) \
+    /// #numbering(suits, 6)
+    /// ```
     numbering: Numbering,
     /// The numbers to apply the numbering to. Must be positive.
     ///
@@ -98,6 +109,8 @@ pub enum Numbering {
     Pattern(NumberingPattern),
     /// A closure mapping from an item's number to content.
     Func(Func),
+    /// A list of symbols that are cycled through and repeated.
+    Symbols(EcoVec<EcoString>),
 }
 
 impl Numbering {
@@ -111,6 +124,9 @@ impl Numbering {
         Ok(match self {
             Self::Pattern(pattern) => Value::Str(pattern.apply(numbers).into()),
             Self::Func(func) => func.call(engine, context, numbers.iter().copied())?,
+            Self::Symbols(symbols) => {
+                Value::Str(cycle_symbols(symbols, numbers.last().copied()).into())
+            }
         })
     }
 
@@ -134,9 +150,29 @@ cast! {
     self => match self {
         Self::Pattern(pattern) => pattern.into_value(),
         Self::Func(func) => func.into_value(),
+        Self::Symbols(symbols) => {
+            symbols.into_iter().map(IntoValue::into_value).collect::<Array>().into_value()
+        }
     },
     v: NumberingPattern => Self::Pattern(v),
     v: Func => Self::Func(v),
+    v: Array => {
+        let symbols: EcoVec<EcoString> =
+            v.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?;
+        if symbols.is_empty() {
+            bail!("symbol list must contain at least one symbol");
+        }
+        Self::Symbols(symbols)
+    },
+}
+
+/// Picks the symbol for the given number from a cycling list of symbols.
+///
+/// Behaves like the `*` counting symbol: Once the list is exhausted, the
+/// symbols are repeated twice, then three times, and so on.
+fn cycle_symbols(symbols: &[EcoString], n: Option<usize>) -> EcoString {
+    let Some(n) = n.filter(|&n| n > 0) else { return '-'.into() };
+    symbols[(n - 1) % symbols.len()].repeat(((n - 1) / symbols.len()) + 1)
 }
 
 /// How to turn a number into text.
@@ -13,10 +13,11 @@ use crate::foundations::{
 };
 use crate::introspection::{Counter, CounterKey, Locatable};
 use crate::layout::{
-    BoxElem, Dir, Em, Fr, HElem, HideElem, Length, Rel, RepeatElem, Spacing,
+    BoxElem, Dir, Fr, HElem, HideElem, Length, Rel, RepeatElem, Spacing,
 };
 use crate::model::{
-    Destination, HeadingElem, NumberingPattern, ParElem, ParbreakElem, Refable,
+    Destination, FirstLineIndent, HeadingElem, NumberingPattern, ParElem, ParbreakElem,
+    Refable,
 };
 use crate::text::{LinebreakElem, LocalName, SpaceElem, TextElem};
 
@@ -265,7 +266,7 @@ impl ShowSet for Packed<OutlineElem> {
         let mut out = Styles::new();
         out.set(HeadingElem::set_outlined(false));
         out.set(HeadingElem::set_numbering(None));
-        out.set(ParElem::set_first_line_indent(Em::new(0.0).into()));
+        out.set(ParElem::set_first_line_indent(FirstLineIndent::default()));
         out
     }
 }
@@ -458,7 +459,11 @@ impl OutlineEntry {
         styles: StyleChain,
     ) -> SourceResult<Option<Self>> {
         let Some(outlinable) = elem.with::<dyn Outlinable>() else {
-            bail!(span, "cannot outline {}", elem.func().name());
+            bail!(
+                span, "cannot outline {}", elem.func().name();
+                hint: "only headings, figures, and equations can be outlined";
+                hint: "to outline other content, wrap it in a `figure` with a custom `kind`"
+            );
         };
 
         let Some(body) = outlinable.outline(engine, styles)? else {
@@ -5,8 +5,8 @@ use typst_utils::singleton;
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, scope, Args, Cast, Construct, Content, NativeElement, Packed, Set, Smart,
-    StyleVec, Unlabellable,
+    cast, dict, elem, scope, Args, Cast, Construct, Content, Dict, NativeElement, Packed,
+    Set, Smart, StyleVec, Unlabellable, Value,
 };
 use crate::introspection::{Count, CounterUpdate, Locatable};
 use crate::layout::{Em, HAlignment, Length, OuterHAlignment};
@@ -37,6 +37,15 @@ use crate::model::Numbering;
 /// let $a$ be the smallest of the
 /// three integers. Then, we ...
 /// ```
+///
+/// # Widows and orphans
+/// By default, paragraphs are not broken across pages such that a single line
+/// ends up alone at the top or bottom of a page. This behavior can be adjusted
+/// through the `widow` and `orphan` [costs]($text.costs) of the text function.
+///
+/// ```typ
+/// #set text(costs: (widow: 0%, orphan: 0%))
+/// ```
 #[elem(scope, title = "Paragraph", Debug, Construct)]
 pub struct ParElem {
     /// The spacing between lines.
@@ -52,6 +61,16 @@ pub struct ParElem {
     /// to `{-0.2em}` to get a baseline gap of exactly `{2em}`. The exact
     /// distribution of the top- and bottom-edge values affects the bounds of
     /// the first and last line.
+    ///
+    /// Typst does not snap lines to a baseline grid. To make the lines of
+    /// adjacent [columns] line up, configure a consistent baseline distance as
+    /// described above and keep the [`spacing`]($par.spacing) between
+    /// paragraphs equal to the leading:
+    ///
+    /// ```typ
+    /// #set text(top-edge: 0.8em, bottom-edge: -0.2em)
+    /// #set par(leading: 0.5em, spacing: 0.5em)
+    /// ```
     #[resolve]
     #[ghost]
     #[default(Em::new(0.65).into())]
@@ -111,15 +130,25 @@ pub struct ParElem {
 
     /// The indent the first line of a paragraph should have.
     ///
-    /// Only the first line of a consecutive paragraph will be indented (not
-    /// the first one in a block or on the page).
+    /// By default, only the first line of a consecutive paragraph will be
+    /// indented (not the first one in a block or on the page). To indent the
+    /// first line of all paragraphs, pass a dictionary with the `amount` of
+    /// the indent and `{all: true}`.
     ///
     /// By typographic convention, paragraph breaks are indicated either by some
     /// space between paragraphs or by indented first lines. Consider reducing
     /// the [paragraph spacing]($block.spacing) to the [`leading`]($par.leading)
     /// when using this property (e.g. using `[#set par(spacing: 0.65em)]`).
+    ///
+    /// ```example
+    /// #set par(first-line-indent: (amount: 1em, all: true))
+    /// This paragraph is indented,
+    /// even though it comes first.
+    ///
+    /// And so is this one.
+    /// ```
     #[ghost]
-    pub first_line_indent: Length,
+    pub first_line_indent: FirstLineIndent,
 
     /// The indent all but the first line of a paragraph should have.
     #[ghost]
@@ -165,6 +194,31 @@ impl Debug for ParElem {
     }
 }
 
+/// Configuration for the first line indent of paragraphs.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct FirstLineIndent {
+    /// The amount of the indent.
+    pub amount: Length,
+    /// Whether to indent all paragraphs, not just consecutive ones.
+    pub all: bool,
+}
+
+cast! {
+    FirstLineIndent,
+    self => if self.all {
+        dict! { "amount" => self.amount, "all" => self.all }.into_value()
+    } else {
+        self.amount.into_value()
+    },
+    amount: Length => Self { amount, all: false },
+    mut dict: Dict => {
+        let amount = dict.take("amount")?.cast()?;
+        let all = dict.take("all").ok().map(Value::cast).transpose()?.unwrap_or(false);
+        dict.finish(&["amount", "all"])?;
+        Self { amount, all }
+    },
+}
+
 /// How to determine line breaks in a paragraph.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum Linebreaks {
@@ -237,7 +237,17 @@ impl Show for Packed<RefElem> {
             return Ok(to_citation(self, engine, styles)?.pack().spanned(span));
         }
 
-        let elem = elem.at(span)?;
+        let elem = elem
+            .map_err(|err| {
+                if engine.introspector.query(&BibliographyElem::elem().select()).is_empty() {
+                    err.with_hint(
+                        "if you meant to cite a source, add a bibliography to the document",
+                    )
+                } else {
+                    err
+                }
+            })
+            .at(span)?;
 
         if let Some(footnote) = elem.to_packed::<FootnoteElem>() {
             return Ok(footnote.into_ref(target).pack().spanned(span));
@@ -0,0 +1,76 @@
+use ecow::EcoString;
+
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, NativeElement, Packed, Show, Smart, StyleChain, Synthesize,
+};
+use crate::introspection::Locatable;
+use crate::layout::{Abs, BlockElem, Length, Sides, Sizing};
+use crate::visualize::Stroke;
+
+/// A placeholder for a digital signature.
+///
+/// Reserves a block of the given size and draws a line at its bottom for a
+/// handwritten signature on paper. In PDF export, the block additionally
+/// becomes an empty signature form field that readers can digitally sign in
+/// their PDF viewer. Typst itself does not sign the document.
+///
+/// # Example
+/// ```example
+/// Signed on behalf of the company:
+/// #signature("ceo", width: 5cm, height: 1.5cm)
+/// ```
+#[elem(Locatable, Synthesize, Show)]
+pub struct SignatureElem {
+    /// The name of the signature field.
+    ///
+    /// PDF viewers show this name to identify the field. It must be unique
+    /// within the document.
+    #[required]
+    pub name: EcoString,
+
+    /// The width of the signature field.
+    #[resolve]
+    #[default(Abs::cm(6.0).into())]
+    pub width: Length,
+
+    /// The height of the signature field.
+    #[resolve]
+    #[default(Abs::cm(2.0).into())]
+    pub height: Length,
+
+    /// How to stroke the line at the bottom of the signature field.
+    ///
+    /// ```example
+    /// #signature("witness", stroke: (dash: "dotted"))
+    /// ```
+    #[fold]
+    pub stroke: Stroke,
+}
+
+impl Synthesize for Packed<SignatureElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        // Store the absolute size so that exporters can write the field
+        // without access to the style chain.
+        let width = self.width(styles);
+        let height = self.height(styles);
+        let elem = self.as_mut();
+        elem.push_width(width.into());
+        elem.push_height(height.into());
+        Ok(())
+    }
+}
+
+impl Show for Packed<SignatureElem> {
+    #[typst_macros::time(name = "signature", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let stroke = self.stroke(styles);
+        Ok(BlockElem::new()
+            .with_width(Smart::Custom(self.width(styles).into()))
+            .with_height(Sizing::Rel(self.height(styles).into()))
+            .with_stroke(Sides::new(None, None, None, Some(Some(stroke))))
+            .pack()
+            .spanned(self.span()))
+    }
+}
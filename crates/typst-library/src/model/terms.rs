@@ -1,10 +1,9 @@
 use typst_utils::Numeric;
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Array, Content, NativeElement, Packed, Show, Smart, StyleChain,
-    Styles,
+    cast, elem, scope, Content, NativeElement, Packed, Show, Smart, StyleChain, Styles,
 };
 use crate::layout::{Dir, Em, HElem, Length, Sides, StackChild, StackElem, VElem};
 use crate::model::{ListItemLike, ListLike, ParElem};
@@ -176,14 +175,7 @@ pub struct TermItem {
 
 cast! {
     TermItem,
-    array: Array => {
-        let mut iter = array.into_iter();
-        let (term, description) = match (iter.next(), iter.next(), iter.next()) {
-            (Some(a), Some(b), None) => (a.cast()?, b.cast()?),
-            _ => bail!("array must contain exactly two entries"),
-        };
-        Self::new(term, description)
-    },
+    (term, description): (Content, Content) => Self::new(term, description),
     v: Content => v.unpack::<Self>().map_err(|_| "expected term item or array")?,
 }
 
@@ -13,6 +13,10 @@ use crate::visualize::{Color, FixedStroke, Paint, Stroke};
 /// ```example
 /// This is #underline[important].
 /// ```
+///
+/// The line is drawn as part of the text it decorates. It thus continues
+/// across line breaks, but skips content that is not text, like images and
+/// shapes. The same holds for [`overline`] and [`strike`].
 #[elem(Show)]
 pub struct UnderlineElem {
     /// How to [stroke] the line.
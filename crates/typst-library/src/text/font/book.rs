@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::Reverse;
 use std::collections::BTreeMap;
 use std::fmt::{self, Debug, Formatter};
@@ -7,6 +8,7 @@ use ttf_parser::{name_id, PlatformId, Tag};
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::exceptions::find_exception;
+use super::woff;
 use crate::text::{Font, FontStretch, FontStyle, FontVariant, FontWeight};
 
 /// Metadata about a collection of fonts.
@@ -158,6 +160,7 @@ impl FontBook {
                 current.variant.style.distance(variant.style),
                 current.variant.stretch.distance(variant.stretch),
                 current.variant.weight.distance(variant.weight),
+                current.variant.weight.deviates_against(variant.weight),
             );
 
             if best_key.map_or(true, |b| key < b) {
@@ -200,14 +203,24 @@ bitflags::bitflags! {
 impl FontInfo {
     /// Compute metadata for font at the `index` of the given data.
     pub fn new(data: &[u8], index: u32) -> Option<Self> {
+        if data.starts_with(woff::SIGNATURE) {
+            return Self::new(&woff::decode(data)?, index);
+        }
+
         let ttf = ttf_parser::Face::parse(data, index).ok()?;
         Self::from_ttf(&ttf)
     }
 
     /// Compute metadata for all fonts in the given data.
     pub fn iter(data: &[u8]) -> impl Iterator<Item = FontInfo> + '_ {
-        let count = ttf_parser::fonts_in_collection(data).unwrap_or(1);
-        (0..count).filter_map(move |index| Self::new(data, index))
+        // Unpack WOFF containers only once instead of for every index.
+        let data = if data.starts_with(woff::SIGNATURE) {
+            woff::decode(data).map(Cow::Owned).unwrap_or_default()
+        } else {
+            Cow::Borrowed(data)
+        };
+        let count = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
+        (0..count).filter_map(move |index| Self::new(&data, index))
     }
 
     /// Compute metadata for a single ttf-parser face.
@@ -512,6 +525,35 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_select_weight_ties() {
+        #[track_caller]
+        fn test(available: &[u16], desired: u16, expected: u16) {
+            let book = FontBook::from_infos(available.iter().map(|&weight| FontInfo {
+                family: "Test".into(),
+                variant: FontVariant {
+                    weight: FontWeight::from_number(weight),
+                    ..Default::default()
+                },
+                flags: FontFlags::empty(),
+                coverage: Coverage::from_vec(vec![]),
+            }));
+            let variant = FontVariant {
+                weight: FontWeight::from_number(desired),
+                ..Default::default()
+            };
+            let index = book.select("test", variant).unwrap();
+            assert_eq!(book.info(index).unwrap().variant.weight.to_number(), expected);
+        }
+
+        test(&[200, 400], 300, 200);
+        test(&[300, 500], 400, 500);
+        test(&[400, 600], 500, 400);
+        test(&[350, 550], 450, 350);
+        test(&[500, 700], 600, 700);
+        test(&[400, 500, 600], 500, 500);
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
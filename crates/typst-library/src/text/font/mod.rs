@@ -5,6 +5,7 @@ pub mod color;
 mod book;
 mod exceptions;
 mod variant;
+mod woff;
 
 pub use self::book::{Coverage, FontBook, FontFlags, FontInfo};
 pub use self::variant::{FontStretch, FontStyle, FontVariant, FontWeight};
@@ -51,7 +52,17 @@ struct Repr {
 
 impl Font {
     /// Parse a font from data and collection index.
+    ///
+    /// Besides OpenType fonts and collections, this also accepts fonts packed
+    /// into a WOFF container.
     pub fn new(data: Bytes, index: u32) -> Option<Self> {
+        // Unpack WOFF containers so that the tables can be parsed directly.
+        let data = if data.starts_with(woff::SIGNATURE) {
+            Bytes::from(woff::decode(&data)?)
+        } else {
+            data
+        };
+
         // Safety:
         // - The slices's location is stable in memory:
         //   - We don't move the underlying vector
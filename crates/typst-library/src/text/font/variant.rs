@@ -129,6 +129,29 @@ impl FontWeight {
     pub fn distance(self, other: Self) -> u16 {
         (self.0 as i16 - other.0 as i16).unsigned_abs()
     }
+
+    /// Whether this weight deviates from a desired weight in the less
+    /// preferred direction, which decides between two equally distant weights.
+    ///
+    /// Like in CSS, lighter fonts are preferred for desired weights below 400
+    /// and heavier fonts for desired weights above 500. In between, heavier
+    /// fonts are preferred as long as they are at most 500 and lighter fonts
+    /// otherwise.
+    pub fn deviates_against(self, desired: Self) -> bool {
+        let (weight, desired) = (self.0, desired.0);
+        if desired < 400 {
+            weight > desired
+        } else if desired <= 500 {
+            if weight > desired {
+                weight > 500
+            } else {
+                // The equally distant heavier weight would be preferred.
+                weight < desired && 2 * desired - weight <= 500
+            }
+        } else {
+            weight < desired
+        }
+    }
 }
 
 impl Default for FontWeight {
@@ -313,6 +336,17 @@ mod tests {
         assert_eq!(d(10, 100), 90);
     }
 
+    #[test]
+    fn test_font_weight_deviates_against() {
+        let d = |a, b| FontWeight(a).deviates_against(FontWeight(b));
+        assert!(!d(200, 300));
+        assert!(d(400, 300));
+        assert!(d(300, 400));
+        assert!(!d(500, 400));
+        assert!(!d(700, 600));
+        assert!(d(500, 600));
+    }
+
     #[test]
     fn test_font_stretch_debug() {
         assert_eq!(FontStretch::EXPANDED.repr(), "125%")
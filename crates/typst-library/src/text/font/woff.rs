@@ -0,0 +1,191 @@
+//! Decoding of WOFF font containers.
+
+use std::io::Read;
+
+/// The signature at the start of a WOFF file.
+pub const SIGNATURE: &[u8; 4] = b"wOFF";
+
+/// Unpacks WOFF data into a plain OpenType font.
+///
+/// WOFF wraps the tables of an OpenType font and compresses each of them
+/// individually with zlib. Returns `None` if the data is malformed.
+pub fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    let mut reader = Reader(data);
+    if reader.take(4)? != SIGNATURE {
+        return None;
+    }
+
+    let flavor = reader.u32()?;
+    let _length = reader.u32()?;
+    let num_tables = reader.u16()?;
+    let _reserved = reader.u16()?;
+    let _total_size = reader.u32()?;
+
+    let mut tables = Vec::with_capacity(usize::from(num_tables));
+    let mut directory = Reader(data.get(44..)?);
+    for _ in 0..num_tables {
+        let tag = directory.u32()?;
+        let offset = directory.u32()? as usize;
+        let comp_length = directory.u32()? as usize;
+        let orig_length = directory.u32()? as usize;
+        let checksum = directory.u32()?;
+
+        let raw = data.get(offset..offset.checked_add(comp_length)?)?;
+        let table = if comp_length < orig_length {
+            let mut decoded = vec![];
+            flate2::read::ZlibDecoder::new(raw)
+                .take(orig_length as u64 + 1)
+                .read_to_end(&mut decoded)
+                .ok()?;
+            if decoded.len() != orig_length {
+                return None;
+            }
+            decoded
+        } else {
+            raw.to_vec()
+        };
+
+        tables.push((tag, checksum, table));
+    }
+
+    // Write the offset table.
+    let mut out = vec![];
+    let records_size = num_tables.checked_mul(16)?;
+    let entry_selector = num_tables.max(1).ilog2() as u16;
+    let search_range = (1 << entry_selector) * 16;
+    out.extend(flavor.to_be_bytes());
+    out.extend(num_tables.to_be_bytes());
+    out.extend(search_range.to_be_bytes());
+    out.extend(entry_selector.to_be_bytes());
+    out.extend(records_size.checked_sub(search_range)?.to_be_bytes());
+
+    // Write the table records. Tables are aligned to four bytes.
+    let mut offset = 12 + usize::from(records_size);
+    for (tag, checksum, table) in &tables {
+        out.extend(tag.to_be_bytes());
+        out.extend(checksum.to_be_bytes());
+        out.extend(u32::try_from(offset).ok()?.to_be_bytes());
+        out.extend(u32::try_from(table.len()).ok()?.to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+
+    // Write the tables themselves.
+    for (_, _, table) in &tables {
+        out.extend(table);
+        out.resize(out.len().next_multiple_of(4), 0);
+    }
+
+    Some(out)
+}
+
+/// Reads big-endian numbers from a byte slice.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Takes the next `n` bytes.
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.0.len() < n {
+            return None;
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Some(head)
+    }
+
+    /// Reads a `u16`.
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    /// Reads a `u32`.
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::Bytes;
+    use crate::text::{Font, FontInfo};
+
+    /// A TrueType font from the test assets.
+    fn font() -> &'static [u8] {
+        typst_dev_assets::fonts()
+            .find(|data| data.starts_with(&0x00010000_u32.to_be_bytes()))
+            .unwrap()
+    }
+
+    /// Splits an OpenType font into its tables.
+    fn tables(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+        (0..num_tables)
+            .map(|i| {
+                let record = &data[12 + 16 * i..28 + 16 * i];
+                let field = |at: usize| {
+                    u32::from_be_bytes(record[at..at + 4].try_into().unwrap()) as usize
+                };
+                let (offset, length) = (field(8), field(12));
+                (record[..4].try_into().unwrap(), &data[offset..offset + length])
+            })
+            .collect()
+    }
+
+    /// Packs tables into a WOFF container, compressing them where it helps.
+    fn encode(tables: &[([u8; 4], &[u8])]) -> Vec<u8> {
+        let mut directory = vec![];
+        let mut data = vec![];
+        let mut offset = 44 + 20 * tables.len();
+        for (tag, table) in tables {
+            let mut compressed = vec![];
+            flate2::read::ZlibEncoder::new(*table, Default::default())
+                .read_to_end(&mut compressed)
+                .unwrap();
+            if compressed.len() >= table.len() {
+                compressed = table.to_vec();
+            }
+
+            directory.extend(tag);
+            for field in [offset, compressed.len(), table.len(), 0] {
+                directory.extend((field as u32).to_be_bytes());
+            }
+            offset += compressed.len().next_multiple_of(4);
+            data.extend(&compressed);
+            data.resize(data.len().next_multiple_of(4), 0);
+        }
+
+        let mut out = SIGNATURE.to_vec();
+        out.extend(0x00010000_u32.to_be_bytes());
+        out.extend((offset as u32).to_be_bytes());
+        out.extend((tables.len() as u16).to_be_bytes());
+        out.resize(44, 0);
+        out.extend(directory);
+        out.extend(data);
+        out
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let otf = font();
+        let woff = encode(&tables(otf));
+        let decoded = decode(&woff).unwrap();
+        assert_eq!(&decoded[..4], &otf[..4]);
+        assert_eq!(tables(&decoded), tables(otf));
+
+        let info = FontInfo::new(otf, 0).unwrap();
+        assert_eq!(FontInfo::new(&woff, 0).unwrap(), info);
+        assert_eq!(FontInfo::iter(&woff).count(), 1);
+
+        let font = Font::new(Bytes::from(woff), 0).unwrap();
+        let original = Font::new(Bytes::from_static(otf), 0).unwrap();
+        assert_eq!(font.info(), original.info());
+        assert_eq!(font.units_per_em(), original.units_per_em());
+    }
+
+    #[test]
+    fn test_decode_rejects_other_data() {
+        assert_eq!(decode(b"OTTO"), None);
+        assert_eq!(decode(b"wOFF\0\0"), None);
+        assert_eq!(decode(&encode(&[])), None);
+    }
+}
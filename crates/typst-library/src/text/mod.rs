@@ -1,5 +1,7 @@
 //! Text handling.
 
+pub mod science;
+
 mod case;
 mod deco;
 mod font;
@@ -9,6 +11,7 @@ mod linebreak;
 #[path = "lorem.rs"]
 mod lorem_;
 mod raw;
+mod ruby;
 mod shift;
 #[path = "smallcaps.rs"]
 mod smallcaps_;
@@ -23,6 +26,7 @@ pub use self::lang::*;
 pub use self::linebreak::*;
 pub use self::lorem_::*;
 pub use self::raw::*;
+pub use self::ruby::*;
 pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
@@ -71,10 +75,12 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<StrikeElem>();
     global.define_elem::<HighlightElem>();
     global.define_elem::<SmallcapsElem>();
+    global.define_elem::<RubyElem>();
     global.define_elem::<RawElem>();
     global.define_func::<lower>();
     global.define_func::<upper>();
     global.define_func::<lorem>();
+    global.define_module(science::module());
 }
 
 /// Customizes the look and layout of text in a variety of ways.
@@ -92,6 +98,19 @@ pub(super) fn define(global: &mut Scope) {
 ///   With a function call.
 /// ])
 /// ```
+///
+/// # Shaping
+/// Text is shaped with the OpenType tables of the selected font. This takes
+/// care of script-specific behavior like the joining of Arabic letters, the
+/// reordering of Indic vowel signs, and emoji sequences joined with zero-width
+/// joiners. For this to work, the font must support the script in question.
+/// The shaping can be influenced per script and language through the
+/// [`script`]($text.script) and [`lang`]($text.lang) parameters.
+///
+/// ```example
+/// #set text(lang: "ar", font: "Noto Sans Arabic")
+/// مرحبا بالعالم
+/// ```
 #[elem(Debug, Construct, PlainText, Repr)]
 pub struct TextElem {
     /// A font family name or priority list of font family names.
@@ -118,6 +137,11 @@ pub struct TextElem {
     ///   system. Note that you can pass the `--ignore-system-fonts` parameter
     ///   to the CLI to ensure Typst won't search for system fonts.
     ///
+    /// Color fonts, as they are commonly used for emoji, are supported in all
+    /// export formats. Their glyphs may be stored as color layers (`COLR`),
+    /// PNG bitmaps (`CBDT` and `sbix`), or SVG images (`SVG`). In PDF export,
+    /// such glyphs are embedded as Type 3 fonts.
+    ///
     /// ```example
     /// #set text(font: "PT Sans")
     /// This is sans-serif.
@@ -188,6 +212,10 @@ pub struct TextElem {
     /// desired weight is not available, Typst selects the font from the family
     /// that is closest in weight.
     ///
+    /// Variable fonts are not supported yet. Typst always uses the default
+    /// instance of a variable font, so the weights you need must be available
+    /// as static font files.
+    ///
     /// If you want to strongly emphasize your text, you should do so using the
     /// [strong] function instead. This makes it easy to adapt the style later
     /// if you change your mind about how to signify the strong emphasis.
@@ -208,6 +236,8 @@ pub struct TextElem {
     /// `{200%}`. When the desired width is not available, Typst selects the
     /// font from the family that is closest in stretch. This will only stretch
     /// the text if a condensed or expanded version of the font is available.
+    /// As with [`weight`]($text.weight), the width axis of variable fonts is
+    /// not used.
     ///
     /// If you want to adjust the amount of space between characters instead of
     /// stretching the glyphs itself, use the [`tracking`]($text.tracking)
@@ -230,7 +260,18 @@ pub struct TextElem {
     /// #set text(size: 20pt)
     /// very #text(1.5em)[big] text
     /// ```
-    #[parse(args.named_or_find("size")?)]
+    #[parse({
+        let size: Option<Spanned<TextSize>> = args.named_or_find("size")?;
+        if let Some(Spanned { v: TextSize(length), span }) = size {
+            if length.abs <= Abs::zero()
+                && length.em <= Em::zero()
+                && (length.abs < Abs::zero() || length.em < Em::zero())
+            {
+                engine.sink.warn(warning!(span, "text size is negative"));
+            }
+        }
+        size.map(|size| size.v)
+    })]
     #[fold]
     #[default(TextSize(Abs::pt(11.0).into()))]
     #[resolve]
@@ -239,9 +280,17 @@ pub struct TextElem {
 
     /// The glyph fill paint.
     ///
+    /// There is no separate opacity setting. To make text translucent, use a
+    /// color with an alpha component, for example by calling
+    /// [`transparentize`]($color.transparentize) on it.
+    ///
     /// ```example
     /// #set text(fill: red)
     /// This text is red.
+    ///
+    /// #text(fill: blue.transparentize(60%))[
+    ///   This text is translucent.
+    /// ]
     /// ```
     #[parse({
         let paint: Option<Spanned<Paint>> = args.named_or_find("fill")?;
@@ -271,6 +320,9 @@ pub struct TextElem {
 
     /// The amount of space that should be added between characters.
     ///
+    /// This is also known as letter spacing. Negative values move the
+    /// characters closer together.
+    ///
     /// ```example
     /// #set text(tracking: 1.5pt)
     /// Distant text.
@@ -287,6 +339,10 @@ pub struct TextElem {
     /// If you want to adjust the amount of space between characters rather than
     /// words, use the [`tracking`]($text.tracking) property instead.
     ///
+    /// In [justified]($par.justify) paragraphs, this is the natural width of
+    /// the spaces. To fill the lines, justification then stretches or shrinks
+    /// them from there.
+    ///
     /// ```example
     /// #set text(spacing: 200%)
     /// Text with distant words.
@@ -296,6 +352,32 @@ pub struct TextElem {
     #[ghost]
     pub spacing: Rel<Length>,
 
+    /// The amount of space after the end of a sentence, relative to the
+    /// [spacing between words]($text.spacing).
+    ///
+    /// By default, sentences are separated like words. Increase this to get
+    /// the wider spacing used in some English typographic traditions. A space
+    /// ends a sentence if it follows a period, question mark, or exclamation
+    /// mark, possibly followed by closing brackets or quotes. To prevent the
+    /// extra space after an abbreviation, use a non-breaking space (`~`). This
+    /// works the same in left-to-right and right-to-left text.
+    ///
+    /// The end of a sentence is only detected if the punctuation and the space
+    /// after it are shaped together, that is, if both have the same styles.
+    /// After styled punctuation, as in `[*The end.* Next]`, the space thus
+    /// stays regular. Conversely, a styled abbreviation like `[_Dr._ X]` is
+    /// not mistaken for the end of a sentence.
+    ///
+    /// ```example
+    /// #set text(sentence-spacing: 200%)
+    /// This is a sentence. Another one!
+    /// See e.g.~the previous line.
+    /// ```
+    #[resolve]
+    #[default(Rel::one())]
+    #[ghost]
+    pub sentence_spacing: Rel<Length>,
+
     /// Whether to automatically insert spacing between CJK and Latin characters.
     ///
     /// ```example
@@ -435,6 +517,9 @@ pub struct TextElem {
     /// - `{ltr}`: Layout text from left to right.
     /// - `{rtl}`: Layout text from right to left.
     ///
+    /// Vertical writing modes, as used for traditional Chinese, Japanese, and
+    /// Korean typesetting, are not supported yet.
+    ///
     /// When writing in right-to-left scripts like Arabic or Hebrew, you should
     /// set the [text language]($text.lang) or direction. While individual runs
     /// of text are automatically layouted in the correct direction, setting the
@@ -489,6 +574,7 @@ pub struct TextElem {
     ///
     /// Currently, the following costs can be customized:
     /// - `hyphenation`: splitting a word across multiple lines
+    /// - `dash`: ending two consecutive lines with a hyphen or dash
     /// - `runt`: ending a paragraph with a line with a single word
     /// - `widow`: leaving a single line of paragraph on the next page
     /// - `orphan`: leaving single line of paragraph on the previous page
@@ -497,6 +583,12 @@ pub struct TextElem {
     /// line, so a higher hyphenation cost can result in awkward justification
     /// spacing.
     ///
+    /// The `dash` cost applies to any break after a dash, whether the dash was
+    /// inserted by hyphenation or is part of the text, when the previous line
+    /// also ends with one. Stacks of hyphens on the right edge of a paragraph
+    /// are distracting, so it is usually sensible to keep this cost at least as
+    /// high as the hyphenation cost.
+    ///
     /// Runts are avoided by placing more or fewer words on previous lines, so a
     /// higher runt cost can result in more awkward in justification spacing.
     ///
@@ -983,7 +1075,10 @@ cast! {
     self => self.0.into_value(),
     v: Smart<Dir> => {
         if v.is_custom_and(|dir| dir.axis() == Axis::Y) {
-            bail!("text direction must be horizontal");
+            bail!(
+                "text direction must be horizontal";
+                hint: "vertical writing modes are not supported yet"
+            );
         }
         Self(v)
     },
@@ -1101,19 +1196,30 @@ cast! {
         .into_iter()
         .map(|v| {
             let tag = v.cast::<EcoString>()?;
-            Ok((Tag::from_bytes_lossy(tag.as_bytes()), 1))
+            Ok((feature_tag(&tag)?, 1))
         })
         .collect::<HintedStrResult<_>>()?),
     values: Dict => Self(values
         .into_iter()
         .map(|(k, v)| {
             let num = v.cast::<u32>()?;
-            let tag = Tag::from_bytes_lossy(k.as_bytes());
+            let tag = feature_tag(&k)?;
             Ok((tag, num))
         })
         .collect::<HintedStrResult<_>>()?),
 }
 
+/// Parses an OpenType feature tag.
+fn feature_tag(tag: &str) -> HintedStrResult<Tag> {
+    if tag.is_empty() || tag.len() > 4 || !tag.is_ascii() {
+        bail!(
+            "invalid font feature tag {}", tag.repr();
+            hint: "feature tags consist of up to four characters, like \"smcp\""
+        );
+    }
+    Ok(Tag::from_bytes_lossy(tag.as_bytes()))
+}
+
 impl Fold for FontFeatures {
     fn fold(self, outer: Self) -> Self {
         Self(self.0.fold(outer.0))
@@ -1213,6 +1319,7 @@ impl Fold for WeightDelta {
 #[non_exhaustive]
 pub struct Costs {
     hyphenation: Option<Ratio>,
+    dash: Option<Ratio>,
     runt: Option<Ratio>,
     widow: Option<Ratio>,
     orphan: Option<Ratio>,
@@ -1224,6 +1331,11 @@ impl Costs {
         self.hyphenation.unwrap_or(Ratio::one())
     }
 
+    #[must_use]
+    pub fn dash(&self) -> Ratio {
+        self.dash.unwrap_or(Ratio::one())
+    }
+
     #[must_use]
     pub fn runt(&self) -> Ratio {
         self.runt.unwrap_or(Ratio::one())
@@ -1245,6 +1357,7 @@ impl Fold for Costs {
     fn fold(self, outer: Self) -> Self {
         Self {
             hyphenation: self.hyphenation.or(outer.hyphenation),
+            dash: self.dash.or(outer.dash),
             runt: self.runt.or(outer.runt),
             widow: self.widow.or(outer.widow),
             orphan: self.orphan.or(outer.orphan),
@@ -1256,6 +1369,7 @@ cast! {
     Costs,
     self => dict![
         "hyphenation" => self.hyphenation(),
+        "dash" => self.dash(),
         "runt" => self.runt(),
         "widow" => self.widow(),
         "orphan" => self.orphan(),
@@ -1263,11 +1377,12 @@ cast! {
     mut v: Dict => {
         let ret = Self {
             hyphenation: v.take("hyphenation").ok().map(|v| v.cast()).transpose()?,
+            dash: v.take("dash").ok().map(|v| v.cast()).transpose()?,
             runt: v.take("runt").ok().map(|v| v.cast()).transpose()?,
             widow: v.take("widow").ok().map(|v| v.cast()).transpose()?,
             orphan: v.take("orphan").ok().map(|v| v.cast()).transpose()?,
         };
-        v.finish(&["hyphenation", "runt", "widow", "orphan"])?;
+        v.finish(&["hyphenation", "dash", "runt", "widow", "orphan"])?;
         ret
     },
 }
@@ -1308,11 +1423,33 @@ fn check_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
 
             engine.sink.warn(warning);
         } else if !found {
-            engine.sink.warn(warning!(
-                list.span,
-                "unknown font family: {}",
-                family.as_str(),
-            ));
+            let mut warning =
+                warning!(list.span, "unknown font family: {}", family.as_str());
+
+            // Catch variants like "Arial Bold" that are not families of their
+            // own.
+            let variant = family.as_str().rsplit_once(' ').filter(|(base, style)| {
+                matches!(
+                    *style,
+                    "thin"
+                        | "light"
+                        | "regular"
+                        | "medium"
+                        | "semibold"
+                        | "bold"
+                        | "black"
+                        | "italic"
+                        | "oblique"
+                ) && book.contains_family(base)
+            });
+            if let Some((base, _)) = variant {
+                warning.hint(eco_format!("did you mean `\"{base}\"`?"));
+                warning.hint(
+                    "font variants are selected with the `weight` and `style` parameters",
+                );
+            }
+
+            engine.sink.warn(warning);
         }
     }
 }
@@ -0,0 +1,63 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::{Alignment, BoxElem, Em, Length, StackChild, StackElem};
+use crate::text::{BottomEdge, BottomEdgeMetric, TextElem, TextSize};
+
+/// Annotates text with small ruby text above it.
+///
+/// Ruby is commonly used in East Asian typography to indicate the
+/// pronunciation of characters, for example with furigana in Japanese. The
+/// annotation is centered above the base text. If it is wider than the base
+/// text, the base text is centered below it and surrounded by extra space.
+///
+/// # Example
+/// ```example
+/// #ruby[東京][とうきょう]に住んでいます。
+/// ```
+#[elem(Show)]
+pub struct RubyElem {
+    /// The font size of the annotation.
+    ///
+    /// ```example
+    /// #ruby(size: 0.7em)[漢字][かんじ]
+    /// ```
+    #[default(TextSize(Em::new(0.5).into()))]
+    pub size: TextSize,
+
+    /// The gap between the annotation and the base text.
+    #[default(Em::new(0.2).into())]
+    pub gap: Length,
+
+    /// The base text to annotate.
+    #[required]
+    pub body: Content,
+
+    /// The annotation to display above the base text.
+    #[required]
+    pub annotation: Content,
+}
+
+impl Show for Packed<RubyElem> {
+    #[typst_macros::time(name = "ruby", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let annotation = self
+            .annotation()
+            .clone()
+            .styled(TextElem::set_size(self.size(styles)));
+
+        // The box's baseline is its bottom edge. Ending the base text at its
+        // baseline thus aligns it with the surrounding text.
+        let stack = StackElem::new(vec![
+            StackChild::Block(annotation.aligned(Alignment::CENTER)),
+            StackChild::Block(self.body().clone().aligned(Alignment::CENTER)),
+        ])
+        .with_spacing(Some(self.gap(styles).into()))
+        .pack()
+        .styled(TextElem::set_bottom_edge(BottomEdge::Metric(
+            BottomEdgeMetric::Baseline,
+        )));
+
+        Ok(BoxElem::new().with_body(Some(stack)).pack().spanned(self.span()))
+    }
+}
@@ -0,0 +1,161 @@
+//! Formatting of scientific notation.
+
+use ecow::{eco_format, EcoString};
+use typst_syntax::Spanned;
+use unscanny::Scanner;
+
+use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::foundations::calc::Num;
+use crate::foundations::{func, repr, Content, IntoValue, Module, Scope, Str};
+use crate::text::{SubElem, SuperElem, TextElem};
+
+/// A module with definitions for scientific notation.
+pub fn module() -> Module {
+    let mut scope = Scope::new();
+    scope.define_func::<si>();
+    scope.define_func::<chem>();
+    Module::new("science", scope)
+}
+
+/// Formats a quantity with a unit.
+///
+/// The value and the unit are separated by a narrow no-break space, as
+/// recommended by the SI. The unit is written as a string:
+///
+/// - Units are separated by spaces for a product with a narrow space, by `.`
+///   or `*` for a product with a dot, and by `/` for a division.
+/// - Each unit can be raised to a power with `^`, as in `{"m^2"}` or
+///   `{"s^-1"}`. Powers are typeset as superscripts.
+///
+/// # Example
+/// ```example
+/// The acceleration is #science.si(9.81, "m/s^2")
+/// and the torque #science.si(12, "N.m").
+/// ```
+#[func(title = "SI Quantity")]
+pub fn si(
+    /// The numeric value of the quantity.
+    value: Num,
+    /// The unit of the quantity.
+    unit: Spanned<Str>,
+) -> SourceResult<Content> {
+    let mut seq = vec![value.into_value().display(), TextElem::packed('\u{202F}')];
+    seq.extend(unit_content(&unit.v).at(unit.span)?);
+    Ok(Content::sequence(seq))
+}
+
+/// Formats a unit string.
+fn unit_content(unit: &str) -> StrResult<Vec<Content>> {
+    let mut seq = vec![];
+    let mut s = Scanner::new(unit);
+    s.eat_whitespace();
+    if s.done() {
+        bail!("unit must not be empty");
+    }
+
+    while !s.done() {
+        let name = s.eat_until(|c: char| c.is_whitespace() || "./*^".contains(c));
+        if name.is_empty() {
+            bail!("expected unit at index {}", s.cursor());
+        }
+        seq.push(TextElem::packed(name));
+
+        if s.eat_if('^') {
+            let sign = if s.eat_if('-') { repr::MINUS_SIGN } else { "" };
+            let power = s.eat_while(|c: char| c.is_ascii_digit());
+            if power.is_empty() {
+                bail!("expected power at index {}", s.cursor());
+            }
+            seq.push(
+                SuperElem::new(TextElem::packed(eco_format!("{sign}{power}"))).pack(),
+            );
+        }
+
+        let spaced = !s.eat_whitespace().is_empty();
+        if s.eat_if(['.', '*']) {
+            seq.push(TextElem::packed('⋅'));
+        } else if s.eat_if('/') {
+            seq.push(TextElem::packed('/'));
+        } else if spaced && !s.done() {
+            seq.push(TextElem::packed('\u{202F}'));
+            continue;
+        } else if !s.done() {
+            bail!("unexpected character at index {}", s.cursor());
+        } else {
+            break;
+        }
+
+        s.eat_whitespace();
+        if s.done() {
+            bail!("expected unit at index {}", s.cursor());
+        }
+    }
+
+    Ok(seq)
+}
+
+/// Formats a chemical formula.
+///
+/// Numbers directly after an element or a closing parenthesis are typeset as
+/// subscripts, while numbers at the start of the formula or after a space stay
+/// regular coefficients. A charge can be added with `^`, as in `{"SO4^2-"}`,
+/// and is typeset as a superscript.
+///
+/// # Example
+/// ```example
+/// #import science: chem
+/// Water is #chem("H2O") and
+/// #chem("2H2 + O2 -> 2H2O") describes its formation.
+/// Sulfate is #chem("SO4^2-").
+/// ```
+#[func(title = "Chemical Formula")]
+pub fn chem(
+    /// The formula to format.
+    formula: Spanned<Str>,
+) -> SourceResult<Content> {
+    Ok(Content::sequence(formula_content(&formula.v).at(formula.span)?))
+}
+
+/// Formats a chemical formula string.
+fn formula_content(formula: &str) -> StrResult<Vec<Content>> {
+    let mut seq = vec![];
+    let mut s = Scanner::new(formula);
+    let mut text = EcoString::new();
+    while let Some(c) = s.peek() {
+        let after_atom = s
+            .before()
+            .chars()
+            .next_back()
+            .is_some_and(|prev| prev.is_alphabetic() || prev == ')' || prev == ']');
+
+        if c.is_ascii_digit() && after_atom {
+            flush(&mut seq, &mut text);
+            let count = s.eat_while(|c: char| c.is_ascii_digit());
+            seq.push(SubElem::new(TextElem::packed(count)).pack());
+        } else if s.eat_if("->") {
+            text.push('→');
+        } else if s.eat_if('^') {
+            flush(&mut seq, &mut text);
+            let charge =
+                s.eat_while(|c: char| c.is_ascii_digit() || c == '+' || c == '-');
+            if charge.is_empty() {
+                bail!("expected charge at index {}", s.cursor());
+            }
+            let charge = charge.replace('-', repr::MINUS_SIGN);
+            seq.push(SuperElem::new(TextElem::packed(charge)).pack());
+        } else {
+            text.push(c);
+            s.eat();
+        }
+    }
+
+    flush(&mut seq, &mut text);
+    Ok(seq)
+}
+
+/// Pushes pending text onto a sequence.
+fn flush(seq: &mut Vec<Content>, text: &mut EcoString) {
+    if !text.is_empty() {
+        seq.push(TextElem::packed(std::mem::take(text)));
+    }
+}
@@ -33,6 +33,12 @@ pub struct SubElem {
     /// The baseline shift for synthetic subscripts. Does not apply if
     /// `typographic` is true and the font has subscript codepoints for the
     /// given `body`.
+    ///
+    /// ```example
+    /// #set sub(typographic: false)
+    /// H#sub[2]O vs.
+    /// H#sub(baseline: 0.4em)[2]O
+    /// ```
     #[default(Em::new(0.2).into())]
     pub baseline: Length,
 
@@ -98,6 +104,12 @@ pub struct SuperElem {
     /// The font size for synthetic superscripts. Does not apply if
     /// `typographic` is true and the font has superscript codepoints for the
     /// given `body`.
+    ///
+    /// ```example
+    /// #set super(typographic: false)
+    /// 1#super[st] vs.
+    /// 1#super(size: 0.8em)[st]
+    /// ```
     #[default(TextSize(Em::new(0.6).into()))]
     pub size: TextSize,
 
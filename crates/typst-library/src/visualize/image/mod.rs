@@ -79,6 +79,9 @@ pub struct ImageElem {
     pub height: Sizing,
 
     /// A text describing the image.
+    ///
+    /// In PDF export, the description is attached to the image so that
+    /// screen readers and other assistive technology can present it.
     pub alt: Option<EcoString>,
 
     /// How the image should adjust itself to a given area (the area is defined
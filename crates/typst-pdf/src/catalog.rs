@@ -1,7 +1,7 @@
 use std::num::NonZeroUsize;
 
 use ecow::eco_format;
-use pdf_writer::types::Direction;
+use pdf_writer::types::{Direction, SigFlags};
 use pdf_writer::writers::PageLabel;
 use pdf_writer::{Finish, Name, Pdf, Ref, Str, TextStr};
 use typst_library::diag::{bail, SourceResult};
@@ -48,6 +48,11 @@ pub fn write_catalog(
         xmp.title([(None, title.as_str())]);
     }
 
+    if let Some(description) = &ctx.document.info.description {
+        info.subject(TextStr::trimmed(description));
+        xmp.description([(None, description.as_str())]);
+    }
+
     let authors = &ctx.document.info.author;
     if !authors.is_empty() {
         // Turns out that if the authors are given in both the document
@@ -187,6 +192,15 @@ pub fn write_catalog(
         }
     }
 
+    // Register the signature fields with the interactive form.
+    let signature_fields = &ctx.references.signature_fields.fields;
+    if !signature_fields.is_empty() {
+        catalog
+            .form()
+            .fields(signature_fields.iter().copied())
+            .sig_flags(SigFlags::SIGNATURES_EXIST);
+    }
+
     if let Some(outline_root_id) = outline_root_id {
         catalog.outlines(outline_root_id);
     }
@@ -321,3 +335,45 @@ fn xmp_date(datetime: Datetime, tz: bool) -> Option<xmp_writer::DateTime> {
         timezone: if tz { Some(Timezone::Utc) } else { None },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, Smart};
+    use typst_library::introspection::Introspector;
+    use typst_library::layout::{Abs, Frame, Page, Size};
+    use typst_library::model::Document;
+
+    use crate::{pdf, PdfOptions};
+
+    /// Exports a document with one empty page and the given description.
+    fn export(description: Option<&str>) -> String {
+        let page = Page {
+            frame: Frame::hard(Size::new(Abs::pt(100.0), Abs::pt(100.0))),
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+        let pages = vec![page];
+        let mut document = Document {
+            introspector: Introspector::new(&pages),
+            pages,
+            ..Default::default()
+        };
+        document.info.description = description.map(Into::into);
+        let data = pdf(&document, &PdfOptions::default()).unwrap();
+        String::from_utf8_lossy(&data).into_owned()
+    }
+
+    #[test]
+    fn test_write_description() {
+        let data = export(Some("A short summary"));
+        assert!(data.contains("/Subject (A short summary)"));
+        assert!(data.contains("<dc:description>"));
+        assert!(data.contains(">A short summary</rdf:li>"));
+
+        let data = export(None);
+        assert!(!data.contains("/Subject"));
+        assert!(!data.contains("<dc:description>"));
+    }
+}
@@ -20,7 +20,7 @@ use typst_library::text::{Font, Glyph, TextItemView};
 
 use crate::font::{base_font_name, write_font_descriptor, CMAP_NAME, SYSTEM_INFO};
 use crate::resources::{Resources, ResourcesRefs};
-use crate::{content, EmExt, PdfChunk, PdfOptions, WithGlobalRefs};
+use crate::{content, deflate, EmExt, PdfChunk, PdfOptions, WithGlobalRefs};
 
 /// Write color fonts in the PDF document.
 ///
@@ -129,7 +129,10 @@ pub fn write_color_fonts(
                     cmap.pair_with_multiple(index as u8, text.chars());
                 }
             }
-            chunk.cmap(cmap_ref, &cmap.finish()).writing_mode(WMode::Horizontal);
+            chunk
+                .cmap(cmap_ref, &deflate(&cmap.finish()))
+                .writing_mode(WMode::Horizontal)
+                .filter(Filter::FlateDecode);
 
             // Write the font descriptor.
             write_font_descriptor(
@@ -750,7 +750,10 @@ fn write_image(
 
     if let Some(alt) = image.alt() {
         if ctx.options.standards.pdfa && alt.len() > Str::PDFA_LIMIT {
-            bail!(span, "the image's alt text is too long");
+            bail!(
+                span, "the image's alt text is too long";
+                hint: "PDF/A limits alt texts to {} bytes", Str::PDFA_LIMIT
+            );
         }
 
         let mut image_span =
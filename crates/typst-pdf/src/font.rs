@@ -1,22 +1,24 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::sync::Arc;
 
-use ecow::{eco_format, EcoString};
+use ecow::{eco_format, EcoString, EcoVec};
 use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
 use pdf_writer::writers::{FontDescriptor, WMode};
 use pdf_writer::{Chunk, Filter, Finish, Name, Rect, Ref, Str};
 use subsetter::GlyphRemapper;
 use ttf_parser::{name_id, GlyphId, Tag};
-use typst_library::diag::{At, SourceResult};
+use typst_library::diag::{bail, At, HintedStrResult, SourceDiagnostic, SourceResult};
 use typst_library::text::Font;
 use typst_syntax::Span;
 use typst_utils::SliceExt;
 
-use crate::{deflate, EmExt, NameExt, PdfChunk, WithGlobalRefs};
+use crate::resources::Resources;
+use crate::{deflate, EmExt, NameExt, PdfChunk, RestrictedFonts, WithGlobalRefs};
 
 const CFF: Tag = Tag::from_bytes(b"CFF ");
 const CFF2: Tag = Tag::from_bytes(b"CFF2");
+const OS2: Tag = Tag::from_bytes(b"OS/2");
 
 const SUBSET_TAG_LEN: usize = 6;
 const IDENTITY_H: &str = "Identity-H";
@@ -41,6 +43,10 @@ pub fn write_fonts(
                 continue;
             }
 
+            if context.options.restricted_fonts == RestrictedFonts::Refuse {
+                check_embedding_permissions(font).at(Span::detached())?;
+            }
+
             let type0_ref = chunk.alloc();
             let cid_ref = chunk.alloc();
             let descriptor_ref = chunk.alloc();
@@ -152,6 +158,57 @@ pub fn write_fonts(
     Ok((chunk, out))
 }
 
+/// Produces a warning for each font in the resources whose license restricts
+/// embedding it.
+pub fn embedding_warnings<R>(resources: &Resources<R>) -> EcoVec<SourceDiagnostic> {
+    let mut seen = HashSet::new();
+    let mut warnings = EcoVec::new();
+    let _ = resources.traverse(&mut |resources| {
+        for font in resources.fonts.items() {
+            if !seen.insert(font.clone()) {
+                continue;
+            }
+
+            if let Err(err) = check_embedding_permissions(font) {
+                warnings.push(
+                    SourceDiagnostic::warning(Span::detached(), err.message().clone())
+                        .with_hints(err.hints().iter().cloned()),
+                );
+            }
+        }
+        Ok(())
+    });
+    warnings
+}
+
+/// Checks that the font's license permits embedding a subset of it.
+fn check_embedding_permissions(font: &Font) -> HintedStrResult<()> {
+    // The `fsType` field of the font's `OS/2` table. Fonts without it don't
+    // restrict embedding.
+    let Some(&[hi, lo]) =
+        font.ttf().raw_face().table(OS2).and_then(|data| data.get(8..10))
+    else {
+        return Ok(());
+    };
+    let fs_type = u16::from_be_bytes([hi, lo]);
+
+    // Only a restricted license without any less restrictive usage permission
+    // forbids embedding.
+    let restricted = fs_type & 0x000F == 0x0002;
+    let no_subsetting = fs_type & 0x0100 != 0;
+    if !restricted && !no_subsetting {
+        return Ok(());
+    }
+
+    let postscript_name = font.find_name(name_id::POST_SCRIPT_NAME);
+    let name = postscript_name.as_deref().unwrap_or(&font.info().family);
+    let what = if restricted { "embedding it" } else { "embedding a subset of it" };
+    bail!(
+        "the license of font {name} does not permit {what}";
+        hint: "try using a different font"
+    );
+}
+
 /// Writes a FontDescriptor dictionary.
 pub fn write_font_descriptor<'a>(
     pdf: &'a mut Chunk,
@@ -276,3 +333,58 @@ fn create_cmap(
     }
     Arc::new(deflate(&cmap.finish()))
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::Bytes;
+
+    use super::*;
+
+    /// Loads a test font and overwrites the `fsType` in its `OS/2` table.
+    fn font(fs_type: u16) -> Font {
+        let mut data = typst_dev_assets::fonts()
+            .find(|data| data.starts_with(&0x00010000_u32.to_be_bytes()))
+            .unwrap()
+            .to_vec();
+
+        // Find the table record of `OS/2` in the table directory.
+        let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+        let record = (0..num_tables)
+            .map(|i| 12 + 16 * i)
+            .find(|&record| &data[record..record + 4] == b"OS/2")
+            .unwrap();
+        let offset =
+            u32::from_be_bytes(data[record + 8..record + 12].try_into().unwrap());
+        let pos = offset as usize + 8;
+        data[pos..pos + 2].copy_from_slice(&fs_type.to_be_bytes());
+
+        Font::new(Bytes::from(data), 0).unwrap()
+    }
+
+    #[test]
+    fn test_check_embedding_permissions() {
+        #[track_caller]
+        fn error(font: &Font) -> String {
+            let name = font.find_name(name_id::POST_SCRIPT_NAME).unwrap();
+            let err = check_embedding_permissions(font).unwrap_err();
+            err.message().replace(&name, "<font>")
+        }
+
+        // Installable and editable embedding.
+        assert!(check_embedding_permissions(&font(0x0000)).is_ok());
+        assert!(check_embedding_permissions(&font(0x0008)).is_ok());
+
+        // Restricted license, with and without a less restrictive permission.
+        assert_eq!(
+            error(&font(0x0002)),
+            "the license of font <font> does not permit embedding it"
+        );
+        assert!(check_embedding_permissions(&font(0x0006)).is_ok());
+
+        // Preview and print embedding without subsetting.
+        assert_eq!(
+            error(&font(0x0104)),
+            "the license of font <font> does not permit embedding a subset of it"
+        );
+    }
+}
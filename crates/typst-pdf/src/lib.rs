@@ -13,6 +13,7 @@ mod outline;
 mod page;
 mod pattern;
 mod resources;
+mod signature;
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
@@ -20,9 +21,10 @@ use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
 use base64::Engine;
+use ecow::EcoVec;
 use pdf_writer::{Chunk, Name, Pdf, Ref, Str, TextStr};
 use serde::{Deserialize, Serialize};
-use typst_library::diag::{bail, SourceResult, StrResult};
+use typst_library::diag::{bail, SourceResult, StrResult, Warned};
 use typst_library::foundations::{Datetime, Smart};
 use typst_library::layout::{Abs, Em, PageRanges, Transform};
 use typst_library::model::Document;
@@ -35,7 +37,7 @@ use crate::catalog::write_catalog;
 use crate::color::{alloc_color_functions_refs, ColorFunctionRefs};
 use crate::color_font::{write_color_fonts, ColorFontSlice};
 use crate::extg::{write_graphic_states, ExtGState};
-use crate::font::write_fonts;
+use crate::font::{embedding_warnings, write_fonts};
 use crate::gradient::{write_gradients, PdfGradient};
 use crate::image::write_images;
 use crate::named_destination::{write_named_destinations, NamedDestinations};
@@ -44,14 +46,38 @@ use crate::pattern::{write_patterns, PdfPattern};
 use crate::resources::{
     alloc_resources_refs, write_resource_dictionaries, Resources, ResourcesRefs,
 };
+use crate::signature::{write_signature_fields, SignatureFields};
 
 /// Export a document into a PDF file.
 ///
 /// Returns the raw bytes making up the PDF file.
-#[typst_macros::time(name = "pdf")]
+///
+/// The file is assembled in memory because resources like fonts can only be
+/// subsetted and written once all pages are known. The compression of page
+/// contents runs in the background while the remaining pages are encoded.
+///
+/// Warnings that arise during the export are discarded. Use [`pdf_warned`] to
+/// receive them.
 pub fn pdf(document: &Document, options: &PdfOptions) -> SourceResult<Vec<u8>> {
-    PdfBuilder::new(document, options)
-        .phase(|builder| builder.run(traverse_pages))?
+    pdf_warned(document, options).map(|warned| warned.output)
+}
+
+/// Export a document into a PDF file and return warnings that arose during
+/// the export, for example for fonts whose license restricts embedding them.
+#[typst_macros::time(name = "pdf")]
+pub fn pdf_warned(
+    document: &Document,
+    options: &PdfOptions,
+) -> SourceResult<Warned<Vec<u8>>> {
+    let builder: PdfBuilder<WithResources> = PdfBuilder::new(document, options)
+        .phase(|builder| builder.run(traverse_pages))?;
+
+    let warnings = match options.restricted_fonts {
+        RestrictedFonts::Warn => embedding_warnings(&builder.state.resources),
+        RestrictedFonts::Embed | RestrictedFonts::Refuse => EcoVec::new(),
+    };
+
+    let output = builder
         .phase(|builder| {
             Ok(GlobalRefs {
                 color_functions: builder.run(alloc_color_functions_refs)?,
@@ -68,11 +94,14 @@ pub fn pdf(document: &Document, options: &PdfOptions) -> SourceResult<Vec<u8>> {
                 gradients: builder.run(write_gradients)?,
                 patterns: builder.run(write_patterns)?,
                 ext_gs: builder.run(write_graphic_states)?,
+                signature_fields: builder.run(write_signature_fields)?,
             })
         })?
         .phase(|builder| builder.run(write_page_tree))?
         .phase(|builder| builder.run(write_resource_dictionaries))?
-        .export_with(write_catalog)
+        .export_with(write_catalog)?;
+
+    Ok(Warned { output, warnings })
 }
 
 /// Settings for PDF export.
@@ -98,6 +127,8 @@ pub struct PdfOptions<'a> {
     pub page_ranges: Option<PageRanges>,
     /// A list of PDF standards that Typst will enforce conformance with.
     pub standards: PdfStandards,
+    /// How to handle fonts whose license restricts embedding them.
+    pub restricted_fonts: RestrictedFonts,
 }
 
 /// Encapsulates a list of compatible PDF standards.
@@ -144,6 +175,24 @@ pub enum PdfStandard {
     A_2b,
 }
 
+/// How to handle fonts whose license restricts embedding them into a PDF.
+///
+/// Fonts declare these restrictions through the embedding permissions in their
+/// `OS/2` table. Typst considers a font restricted if its license does not
+/// permit embedding at all or if it forbids subsetting, which Typst always
+/// performs.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RestrictedFonts {
+    /// Embed restricted fonts anyway. It is up to the user to make sure that
+    /// they are allowed to distribute the font.
+    Embed,
+    /// Embed restricted fonts, but emit a warning for each of them.
+    #[default]
+    Warn,
+    /// Fail the export if a restricted font is used.
+    Refuse,
+}
+
 /// A struct to build a PDF following a fixed succession of phases.
 ///
 /// This type uses generics to represent its current state. `S` (for "state") is
@@ -272,6 +321,8 @@ struct References {
     patterns: HashMap<PdfPattern, Ref>,
     /// The IDs of written external graphics states.
     ext_gs: HashMap<ExtGState, Ref>,
+    /// The IDs of written signature fields.
+    signature_fields: SignatureFields,
 }
 
 /// At this point, the references have been assigned to all resources. The page
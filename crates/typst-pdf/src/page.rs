@@ -178,6 +178,8 @@ fn write_page(
         }
     }
 
+    annotations.extend_from_slice(ctx.references.signature_fields.on_page(i));
+
     let mut page_writer = chunk.page(page_ref);
     page_writer.parent(page_tree_ref);
 
@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+
+use pdf_writer::types::{AnnotationFlags, FieldType};
+use pdf_writer::{Finish, Rect, Ref, TextStr};
+use typst_library::diag::{bail, SourceResult};
+use typst_library::foundations::{NativeElement, Packed, StyleChain};
+use typst_library::model::SignatureElem;
+
+use crate::{AbsExt, PdfChunk, Renumber, WithGlobalRefs};
+
+/// The signature fields of the document.
+///
+/// Each field is written as a merged field and widget annotation dictionary,
+/// which must be listed both in the annotations of its page and in the
+/// document's interactive form.
+#[derive(Default)]
+pub struct SignatureFields {
+    /// The fields in document order.
+    pub fields: Vec<Ref>,
+    /// The fields on each page, keyed by page index.
+    pub by_page: HashMap<usize, Vec<Ref>>,
+}
+
+impl SignatureFields {
+    /// The fields that are placed on the page with the given index.
+    pub fn on_page(&self, index: usize) -> &[Ref] {
+        self.by_page.get(&index).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl Renumber for SignatureFields {
+    fn renumber(&mut self, offset: i32) {
+        self.fields.renumber(offset);
+        self.by_page.renumber(offset);
+    }
+}
+
+/// Writes an empty signature field for each signature element.
+pub fn write_signature_fields(
+    context: &WithGlobalRefs,
+) -> SourceResult<(PdfChunk, SignatureFields)> {
+    let mut chunk = PdfChunk::new();
+    let mut out = SignatureFields::default();
+    let mut seen = HashSet::new();
+
+    let elems = context.document.introspector.query(&SignatureElem::elem().select());
+
+    for elem in elems.iter() {
+        let elem = elem.to_packed::<SignatureElem>().unwrap();
+        if !seen.insert(elem.name()) {
+            bail!(
+                elem.span(), "duplicate signature field name";
+                hint: "each signature field must have a unique name"
+            );
+        }
+
+        let Some((index, rect)) = field_rect(context, elem) else { continue };
+        let Some(page_ref) = context.globals.pages[index] else { continue };
+
+        let field_ref = chunk.alloc();
+        let appearance_ref = chunk.alloc();
+
+        // Readers draw the signature into the field's appearance once it is
+        // signed. Until then, it stays empty, as the visible parts of the
+        // placeholder are part of the page content.
+        let bbox = Rect::new(0.0, 0.0, rect.x2 - rect.x1, rect.y2 - rect.y1);
+        chunk.form_xobject(appearance_ref, &[]).bbox(bbox);
+
+        let mut field = chunk.form_field(field_ref);
+        field
+            .partial_name(TextStr(elem.name()))
+            .field_type(FieldType::Signature);
+
+        let mut annotation = field.into_annotation();
+        annotation.rect(rect).page(page_ref).flags(AnnotationFlags::PRINT);
+        annotation.appearance().normal().stream(appearance_ref);
+        annotation.finish();
+
+        out.fields.push(field_ref);
+        out.by_page.entry(index).or_default().push(field_ref);
+    }
+
+    Ok((chunk, out))
+}
+
+/// Determines the page index and the rectangle, in PDF coordinates, covered by
+/// a signature field. Returns `None` if the page is not exported.
+fn field_rect(
+    context: &WithGlobalRefs,
+    elem: &Packed<SignatureElem>,
+) -> Option<(usize, Rect)> {
+    let loc = elem.location()?;
+    let pos = context.document.introspector.position(loc);
+    let index = pos.page.get() - 1;
+    let page = context.pages.get(index)?.as_ref()?;
+
+    // The size was made absolute during synthesis, so the default style chain
+    // suffices to resolve it.
+    let width = elem.width(StyleChain::default());
+    let height = elem.height(StyleChain::default());
+
+    let x1 = pos.point.x;
+    let y1 = page.content.size.y - pos.point.y - height;
+    Some((
+        index,
+        Rect::new(
+            x1.to_f32(),
+            y1.to_f32(),
+            (x1 + width).to_f32(),
+            (y1 + height).to_f32(),
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, Smart};
+    use typst_library::introspection::{Introspector, Location, Tag};
+    use typst_library::layout::{Abs, Frame, FrameItem, Page, Point, Size};
+    use typst_library::model::Document;
+
+    use super::*;
+    use crate::{pdf, PdfOptions};
+
+    /// Builds a document with one page per list of field names, placing the
+    /// fields below each other.
+    fn document(pages: &[&[&str]]) -> Document {
+        let mut hash = 0;
+        let pages: Vec<_> = pages
+            .iter()
+            .map(|names| {
+                let mut frame = Frame::hard(Size::new(Abs::pt(200.0), Abs::pt(200.0)));
+                for (i, name) in names.iter().enumerate() {
+                    hash += 1;
+                    let elem = SignatureElem::new((*name).into())
+                        .pack()
+                        .located(Location::new(hash));
+                    let pos = Point::new(Abs::pt(10.0), Abs::pt(60.0 * i as f64));
+                    frame.push(pos, FrameItem::Tag(Tag::Start(elem)));
+                }
+                Page {
+                    frame,
+                    fill: Smart::Auto,
+                    numbering: None,
+                    supplement: Content::empty(),
+                    number: 1,
+                }
+            })
+            .collect();
+
+        let introspector = Introspector::new(&pages);
+        Document { pages, introspector, ..Default::default() }
+    }
+
+    /// Counts the occurrences of a pattern in the exported bytes.
+    fn count(data: &[u8], pattern: &[u8]) -> usize {
+        data.windows(pattern.len())
+            .filter(|window| *window == pattern)
+            .count()
+    }
+
+    #[test]
+    fn test_signature_fields_on_multiple_pages() {
+        let document = document(&[&["ceo", "cfo"], &[], &["witness"]]);
+        let data = pdf(&document, &PdfOptions::default()).unwrap();
+        assert_eq!(count(&data, b"/FT /Sig"), 3);
+        assert_eq!(count(&data, b"/T (witness)"), 1);
+        assert_eq!(count(&data, b"/AcroForm"), 1);
+    }
+
+    #[test]
+    fn test_signature_fields_duplicate_name() {
+        let document = document(&[&["ceo"], &["ceo"]]);
+        let errors = pdf(&document, &PdfOptions::default()).unwrap_err();
+        assert_eq!(errors[0].message, "duplicate signature field name");
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{is_newline, parse, split_newlines, SyntaxError, SyntaxKind, SyntaxNode};
+
+/// The number of spaces per indentation level.
+const INDENT: usize = 2;
+
+/// Formats Typst source code into a canonical form.
+///
+/// The formatter is conservative and only changes whitespace in code, so that
+/// the result always has the same meaning as the input:
+/// - Spaces before commas and colons are removed and exactly one space is put
+///   after them.
+/// - Spaces directly inside of parentheses are removed.
+/// - Exactly one space is put around the `=` of let bindings.
+/// - Lines in code blocks and multi-line collections are indented by two
+///   spaces per nesting level.
+/// - Trailing spaces in code are removed and consecutive blank lines are
+///   collapsed into one.
+///
+/// Markup and math are left untouched, except for the code embedded in them.
+///
+/// Returns the syntax errors in the source if it cannot be parsed without
+/// errors.
+pub fn format(text: &str) -> Result<String, Vec<SyntaxError>> {
+    let root = parse(text);
+    if root.erroneous() {
+        return Err(root.errors());
+    }
+
+    let mut formatter = Formatter {
+        buf: String::with_capacity(text.len()),
+        indents: vec![],
+    };
+    formatter.node(&root, false);
+    Ok(formatter.buf)
+}
+
+/// Writes the formatted source code of a syntax tree.
+struct Formatter {
+    /// The formatted output.
+    buf: String,
+    /// The indentation for the contents of each enclosing delimited code
+    /// construct.
+    indents: Vec<usize>,
+}
+
+impl Formatter {
+    /// Formats a node and its descendants.
+    fn node(&mut self, node: &SyntaxNode, math: bool) {
+        let kind = node.kind();
+        let math = math || matches!(kind, SyntaxKind::Equation | SyntaxKind::Math);
+        if math || node.children().len() == 0 {
+            self.buf.push_str(&node.clone().into_text());
+            return;
+        }
+
+        let delimited = is_delimited(kind);
+        if delimited {
+            self.indents.push(self.line_indent() + INDENT);
+        }
+
+        let children: Vec<_> = node.children().collect();
+        for (i, child) in children.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|j| children[j].kind());
+            let next = children.get(i + 1).map(|child| child.kind());
+            match child.kind() {
+                SyntaxKind::Space
+                    if (is_indented(kind) || is_spaced(kind)) && has_newline(child) =>
+                {
+                    self.newlines(child, next);
+                }
+                SyntaxKind::Space if is_spaced(kind) => {
+                    let removed =
+                        matches!(next, Some(SyntaxKind::Comma | SyntaxKind::Colon))
+                            || prev.is_some_and(is_opening)
+                            || next.is_some_and(is_closing);
+                    if !removed {
+                        self.buf.push(' ');
+                    }
+                }
+                SyntaxKind::Space if is_indented(kind) => {
+                    self.buf.push(' ');
+                }
+                SyntaxKind::Space
+                    if is_assignment(kind)
+                        && !has_newline(child)
+                        && (prev == Some(SyntaxKind::Eq)
+                            || next == Some(SyntaxKind::Eq)) =>
+                {
+                    self.buf.push(' ');
+                }
+                SyntaxKind::Eq if is_assignment(kind) => {
+                    if prev != Some(SyntaxKind::Space) {
+                        self.buf.push(' ');
+                    }
+                    self.buf.push('=');
+                    if next.is_some_and(|next| next != SyntaxKind::Space) {
+                        self.buf.push(' ');
+                    }
+                }
+                SyntaxKind::Comma | SyntaxKind::Colon if is_spaced(kind) => {
+                    self.buf.push_str(child.text());
+                    if next.is_some_and(|next| {
+                        next != SyntaxKind::Space && !is_closing(next)
+                    }) {
+                        self.buf.push(' ');
+                    }
+                }
+                _ => self.node(child, math),
+            }
+        }
+
+        if delimited {
+            self.indents.pop();
+        }
+    }
+
+    /// Replaces a space containing newlines with at most one blank line and
+    /// the indentation of the next line.
+    fn newlines(&mut self, space: &SyntaxNode, next: Option<SyntaxKind>) {
+        let count = split_newlines(space.text()).len() - 1;
+        for _ in 0..count.min(2) {
+            self.buf.push('\n');
+        }
+
+        let indent = self.indents.last().copied().unwrap_or(0);
+        let indent = if next.map_or(true, is_closing) {
+            indent.saturating_sub(INDENT)
+        } else {
+            indent
+        };
+
+        self.buf.extend(std::iter::repeat(' ').take(indent));
+    }
+
+    /// The indentation of the current output line.
+    fn line_indent(&self) -> usize {
+        let line = self.buf.rsplit(is_newline).next().unwrap_or_default();
+        line.len() - line.trim_start_matches(' ').len()
+    }
+}
+
+/// Whether the node kind is a code construct with delimiters whose contents
+/// are indented.
+fn is_delimited(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::CodeBlock
+            | SyntaxKind::Args
+            | SyntaxKind::Params
+            | SyntaxKind::Array
+            | SyntaxKind::Dict
+            | SyntaxKind::Destructuring
+    )
+}
+
+/// Whether the lines in a node of this kind are indented by the formatter.
+fn is_indented(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::Code || is_delimited(kind)
+}
+
+/// Whether the spaces around commas and colons in a node of this kind are
+/// normalized by the formatter.
+fn is_spaced(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Args
+            | SyntaxKind::Params
+            | SyntaxKind::Array
+            | SyntaxKind::Dict
+            | SyntaxKind::Destructuring
+            | SyntaxKind::Named
+            | SyntaxKind::Keyed
+    )
+}
+
+/// Whether the `=` in a node of this kind is surrounded by single spaces.
+fn is_assignment(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::LetBinding | SyntaxKind::Closure)
+}
+
+/// Whether the kind is an opening delimiter.
+fn is_opening(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::LeftParen | SyntaxKind::LeftBrace)
+}
+
+/// Whether the kind is a closing delimiter.
+fn is_closing(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::RightParen | SyntaxKind::RightBrace)
+}
+
+/// Whether a space node spans multiple lines.
+fn has_newline(space: &SyntaxNode) -> bool {
+    space.text().chars().any(is_newline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn test(text: &str, goal: &str) {
+        let formatted = format(text).unwrap();
+        assert_eq!(formatted, goal);
+        assert_eq!(format(&formatted).unwrap(), goal);
+    }
+
+    #[test]
+    fn test_format_spacing() {
+        test("#f(a,b , c)", "#f(a, b, c)");
+        test("#let x = ( a : 1,b:2 )", "#let x = (a: 1, b: 2)");
+        test("#let (a ,b) = (1,2)", "#let (a, b) = (1, 2)");
+        test("#let f( x ,y : 2)= x", "#let f(x, y: 2) = x");
+        test("#let x=1", "#let x = 1");
+        test("#let x   =   1", "#let x = 1");
+        test("#(:)", "#(:)");
+        test("#f(a, // note\n  b)", "#f(a, // note\n  b)");
+        test("#f(a: // note\n  1)", "#f(a: // note\n  1)");
+        test("#(a: // note\n    1)", "#(a: // note\n  1)");
+    }
+
+    #[test]
+    fn test_format_indentation() {
+        test("#{\nlet x = 1\n      x\n}", "#{\n  let x = 1\n  x\n}");
+        test("#{\n  if true {\n x\n  }\n}", "#{\n  if true {\n    x\n  }\n}");
+        test("#f(\n      a,\n   b,\n    )", "#f(\n  a,\n  b,\n)");
+        test("#{\n  let a = 1   \n\n\n\n  a\n}", "#{\n  let a = 1\n\n  a\n}");
+    }
+
+    #[test]
+    fn test_format_keeps_markup_and_math() {
+        test("Hello ,  world :  *strong* ", "Hello ,  world :  *strong* ");
+        test("$ f(a ,b) $ and #g( x )", "$ f(a ,b) $ and #g(x)");
+        test("#[\n      - item\n  - item ]", "#[\n      - item\n  - item ]");
+    }
+
+    #[test]
+    fn test_format_rejects_errors() {
+        assert!(format("#f(a,").is_err());
+    }
+}
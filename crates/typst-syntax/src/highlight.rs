@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::{ast, LinkedNode, SyntaxKind, SyntaxNode};
 
 /// A syntax highlighting tag.
@@ -363,6 +365,47 @@ fn is_ident(node: &LinkedNode) -> bool {
     matches!(node.kind(), SyntaxKind::Ident | SyntaxKind::MathIdent)
 }
 
+/// Highlight a syntax tree into a flat list of tagged byte ranges.
+///
+/// Each leaf is tagged with its own tag or, if it has none, the tag of its
+/// innermost highlighted ancestor. In contrast to calling [`highlight`] on
+/// every node, the resulting ranges never overlap, which is what editors
+/// typically expect from a token stream. The ranges are sorted, adjacent ranges
+/// with the same tag are merged, and text without a tag is skipped.
+pub fn highlight_ranges(root: &SyntaxNode) -> Vec<(Range<usize>, Tag)> {
+    let mut ranges = vec![];
+    highlight_ranges_impl(&mut ranges, &LinkedNode::new(root), None);
+    ranges
+}
+
+/// Highlight one source node, collecting tagged leaf ranges.
+fn highlight_ranges_impl(
+    ranges: &mut Vec<(Range<usize>, Tag)>,
+    node: &LinkedNode,
+    outer: Option<Tag>,
+) {
+    let tag = highlight(node).or(outer);
+    if node.get().children().len() > 0 {
+        for child in node.children() {
+            highlight_ranges_impl(ranges, &child, tag);
+        }
+        return;
+    }
+
+    let Some(tag) = tag else { return };
+    let range = node.range();
+    if range.is_empty() {
+        return;
+    }
+
+    match ranges.last_mut() {
+        Some((last, last_tag)) if *last_tag == tag && last.end == range.start => {
+            last.end = range.end;
+        }
+        _ => ranges.push((range, tag)),
+    }
+}
+
 /// Highlight a node to an HTML `code` element.
 ///
 /// This uses these [CSS classes for categories](Tag::css_class).
@@ -411,8 +454,6 @@ fn highlight_html_impl(html: &mut String, node: &LinkedNode) {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::Range;
-
     use super::*;
 
     #[test]
@@ -463,4 +504,30 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_highlight_ranges() {
+        use Tag::*;
+
+        #[track_caller]
+        fn test(text: &str, goal: &[(Range<usize>, Tag)]) {
+            assert_eq!(highlight_ranges(&crate::parse(text)), goal);
+        }
+
+        test("= *AB*", &[(0..2, Heading), (2..6, Strong)]);
+        test(
+            "#let f(x) = x",
+            &[
+                (0..4, Keyword),
+                (5..6, Function),
+                (6..7, Punctuation),
+                (8..9, Punctuation),
+                (10..11, Operator),
+            ],
+        );
+        test(
+            "// hi\n#let x = 1",
+            &[(0..5, Comment), (6..10, Keyword), (13..14, Operator), (15..16, Number)],
+        );
+    }
 }
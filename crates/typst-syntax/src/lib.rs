@@ -1,9 +1,19 @@
 //! Parser and syntax tree for Typst.
+//!
+//! The lexer is not exposed on its own because Typst's lexical modes depend
+//! on the surrounding syntax: A `#` in markup, for instance, switches to code
+//! until the embedded expression ends. Tools that need tokens, like
+//! highlighters, should instead [`parse`] the text and walk the leaves of the
+//! resulting tree, for example with [`LinkedNode::leftmost_leaf`] and
+//! [`LinkedNode::next_leaf`]. Each leaf carries its kind, its text, and its
+//! position in the source, and the leaves' texts concatenate to the source
+//! text.
 
 pub mod ast;
 pub mod package;
 
 mod file;
+mod format;
 mod highlight;
 mod kind;
 mod lexer;
@@ -16,7 +26,8 @@ mod source;
 mod span;
 
 pub use self::file::FileId;
-pub use self::highlight::{highlight, highlight_html, Tag};
+pub use self::format::format;
+pub use self::highlight::{highlight, highlight_html, highlight_ranges, Tag};
 pub use self::kind::SyntaxKind;
 pub use self::lexer::{
     is_id_continue, is_id_start, is_ident, is_newline, is_valid_label_literal_id,
@@ -336,7 +336,8 @@ struct LeafNode {
     /// What kind of node this is (each kind would have its own struct in a
     /// strongly typed AST).
     kind: SyntaxKind,
-    /// The source text of the node.
+    /// The source text of the node. Most tokens are short enough to be stored
+    /// inline by `EcoString`, so that they do not need a heap allocation.
     text: EcoString,
     /// The node's span.
     span: Span,
@@ -1054,4 +1055,28 @@ mod tests {
         assert_eq!(prev.text(), "=");
         assert_eq!(leaf.text(), "10");
     }
+
+    #[test]
+    fn test_lossless_round_trip() {
+        #[track_caller]
+        fn test(parse: fn(&str) -> SyntaxNode, text: &str) {
+            let root = parse(text);
+            assert_eq!(root.len(), text.len());
+            assert_eq!(root.clone().into_text(), text);
+        }
+
+        test(crate::parse, "= Head  /* inline */ \n\n  _emph_ // line\n");
+        test(crate::parse, "#let f( x ,y)= {\n  x  +  y // sum\n}\n#f(1,  2)");
+        test(crate::parse, "#set text( 12pt , fill : red )\n#[unterminated");
+        test(crate::parse_code, "let  x = ( a : 1 ,)\n/* doc */ x.a");
+        test(crate::parse_math, "sum_( i  = 0 )^n  i // math comment");
+    }
+
+    #[test]
+    fn test_typed_layer_round_trip() {
+        let root = crate::parse("#let x = 1 /* keep */\n#x");
+        let markup = root.cast::<crate::ast::Markup>().unwrap();
+        assert_eq!(markup.to_untyped(), &root);
+        assert_eq!(markup.to_untyped().clone().into_text(), root.clone().into_text());
+    }
 }
@@ -1215,7 +1215,7 @@ fn args(p: &mut Parser) {
             let mut seen = HashSet::new();
             while !p.current().is_terminator() {
                 if !p.at_set(set::ARG) {
-                    p.unexpected();
+                    p.unexpected_until_comma();
                     continue;
                 }
 
@@ -2029,6 +2029,82 @@ impl<'s> Parser<'s> {
         self.eat_and_get().unexpected();
     }
 
+    /// Consume the tokens up to the next comma or closing delimiter of the
+    /// enclosing group and produce a single error stating that the first of
+    /// them was unexpected.
+    ///
+    /// This prevents a cascade of errors for one bad argument. Content blocks
+    /// among the skipped tokens are parsed as markup so that their brackets
+    /// stay balanced, and errors within the skipped tokens are kept.
+    fn unexpected_until_comma(&mut self) {
+        self.trim_errors();
+        let Marker(start) = self.marker();
+        self.unexpected();
+
+        let mut depth = 0;
+        loop {
+            let kind = self.current();
+            match kind {
+                SyntaxKind::End => break,
+                SyntaxKind::Comma if depth == 0 => break,
+                _ if kind.is_terminator() && depth == 0 => break,
+                SyntaxKind::LeftBracket => {
+                    content_block(self);
+                    continue;
+                }
+                SyntaxKind::LeftParen | SyntaxKind::LeftBrace => depth += 1,
+                SyntaxKind::RightParen | SyntaxKind::RightBrace => depth -= 1,
+                _ => {}
+            }
+            self.balanced &= !kind.is_grouping();
+            self.eat();
+        }
+
+        // Merge the runs of skipped tokens into error nodes, keeping the nodes
+        // that already contain errors of their own.
+        let Marker(end) = self.before_trivia();
+        let nodes: Vec<_> = self.nodes.drain(start..end).collect();
+        let mut merged = Vec::with_capacity(nodes.len());
+        let mut run = vec![];
+        for (i, node) in nodes.into_iter().enumerate() {
+            if i > 0 && node.erroneous() {
+                Self::merge_unexpected(&mut run, &mut merged);
+                merged.push(node);
+            } else if run.is_empty() && node.kind().is_trivia() {
+                merged.push(node);
+            } else {
+                run.push(node);
+            }
+        }
+        Self::merge_unexpected(&mut run, &mut merged);
+        let rest = self.nodes.split_off(start);
+        self.nodes.extend(merged);
+        self.nodes.extend(rest);
+    }
+
+    /// Merge a run of skipped nodes into a single error node, stating that the
+    /// first of them was unexpected. Trailing trivia stays outside the error.
+    fn merge_unexpected(run: &mut Vec<SyntaxNode>, out: &mut Vec<SyntaxNode>) {
+        let trivia = run.iter().rev().take_while(|node| node.kind().is_trivia()).count();
+        let trailing = run.split_off(run.len() - trivia);
+        if run.len() == 1 && run[0].kind().is_error() {
+            out.append(run);
+        }
+
+        if let Some(first) = run.first() {
+            let error = match first.errors().into_iter().next() {
+                Some(error) => error,
+                None => {
+                    SyntaxError::new(eco_format!("unexpected {}", first.kind().name()))
+                }
+            };
+            let text = run.drain(..).map(SyntaxNode::into_text).collect::<EcoString>();
+            out.push(SyntaxNode::error(error, text));
+        }
+
+        out.extend(trailing);
+    }
+
     /// Remove trailing errors with zero length.
     fn trim_errors(&mut self) {
         let Marker(end) = self.before_trivia();
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use typst_utils::LazyHash;
 
 use crate::reparser::reparse;
-use crate::{is_newline, parse, FileId, LinkedNode, Span, SyntaxNode, VirtualPath};
+use crate::{is_newline, parse, FileId, LinkedNode, Side, Span, SyntaxNode, VirtualPath};
 
 /// A source file.
 ///
@@ -163,6 +163,16 @@ impl Source {
         LinkedNode::new(self.root()).find(span)
     }
 
+    /// Find the span of the innermost node at the given byte index.
+    ///
+    /// Spans identify nodes across edits: Nodes that are not reparsed after an
+    /// edit keep their span, so tools can use them to correlate the nodes of
+    /// different versions of a file.
+    pub fn span_at(&self, byte_idx: usize) -> Option<Span> {
+        let leaf = LinkedNode::new(self.root()).leaf_at(byte_idx, Side::After)?;
+        Some(leaf.span())
+    }
+
     /// Get the byte range for the given span in this file.
     ///
     /// Returns `None` if the span does not point into this source file.
@@ -443,4 +453,17 @@ mod tests {
         // Test removing everything.
         test(TEST, 0..21, "", "");
     }
+
+    #[test]
+    fn test_source_file_stable_spans() {
+        let mut source = Source::detached("#let x = 1\n#f(x)\n= Heading");
+        let heading = source.span_at(19).unwrap();
+        let call = source.span_at(12).unwrap();
+
+        // Spans of nodes outside of the edit stay the same.
+        source.edit(9..10, "100");
+        assert_eq!(source.span_at(21), Some(heading));
+        assert_eq!(source.span_at(14), Some(call));
+        assert_eq!(source.find(heading).unwrap().text(), "Heading");
+    }
 }
@@ -45,7 +45,7 @@ use comemo::{Track, Tracked, Validate};
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use typst_library::diag::{warning, FileError, SourceDiagnostic, SourceResult, Warned};
 use typst_library::engine::{Engine, Route, Sink, Traced};
-use typst_library::foundations::{StyleChain, Styles, Value};
+use typst_library::foundations::{Content, StyleChain, Styles, Value};
 use typst_library::introspection::Introspector;
 use typst_library::model::Document;
 use typst_library::routines::Routines;
@@ -74,6 +74,23 @@ pub fn trace(world: &dyn World, span: Span) -> EcoVec<(Value, Option<Styles>)> {
     sink.values()
 }
 
+/// Lay out content that was not produced by the main source file, for example
+/// by evaluating a string, into a document.
+///
+/// - Returns `Ok(document)` if there were no fatal errors.
+/// - Returns `Err(errors)` if there were fatal errors.
+#[typst_macros::time]
+pub fn layout_content(
+    world: &dyn World,
+    content: &Content,
+) -> Warned<SourceResult<Document>> {
+    let mut sink = Sink::new();
+    let output =
+        layout_impl(world.track(), Traced::default().track(), &mut sink, content)
+            .map_err(deduplicate);
+    Warned { output, warnings: sink.warnings() }
+}
+
 /// The internal implementation of `compile` with a bit lower-level interface
 /// that is also used by `trace`.
 fn compile_impl(
@@ -81,9 +98,6 @@ fn compile_impl(
     traced: Tracked<Traced>,
     sink: &mut Sink,
 ) -> SourceResult<Document> {
-    let library = world.library();
-    let styles = StyleChain::new(&library.styles);
-
     // Fetch the main source file once.
     let main = world.main();
     let main = world
@@ -101,6 +115,19 @@ fn compile_impl(
     )?
     .content();
 
+    layout_impl(world, traced, sink, &content)
+}
+
+/// Lays out content, relayouting until all introspections stabilize.
+fn layout_impl(
+    world: Tracked<dyn World + '_>,
+    traced: Tracked<Traced>,
+    sink: &mut Sink,
+    content: &Content,
+) -> SourceResult<Document> {
+    let library = world.library();
+    let styles = StyleChain::new(&library.styles);
+
     let mut iter = 0;
     let mut subsink;
     let mut document = Document::default();
@@ -126,7 +153,7 @@ fn compile_impl(
         };
 
         // Layout!
-        document = (engine.routines.layout_document)(&mut engine, &content, styles)?;
+        document = (engine.routines.layout_document)(&mut engine, content, styles)?;
         iter += 1;
 
         if timed!("check stabilized", document.introspector.validate(&constraint)) {
@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typst_syntax::{parse, Source, Span};
+
+fuzz_target!(|text: &str| {
+    // The input holds the original text, the replacement, and a third part
+    // whose length determines the length of the replaced range, separated by
+    // NUL characters.
+    let mut parts = text.splitn(3, '\0');
+    let (Some(prev), Some(with), Some(len)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return;
+    };
+
+    let start = with.len() % (prev.len() + 1);
+    let end = (start + len.len()).min(prev.len());
+    if !prev.is_char_boundary(start) || !prev.is_char_boundary(end) {
+        return;
+    }
+
+    // Incremental reparsing must yield the same tree as parsing from scratch.
+    let mut source = Source::detached(prev);
+    source.edit(start..end, with);
+    let mut found = source.root().clone();
+    let mut expected = parse(source.text());
+    found.synthesize(Span::detached());
+    expected.synthesize(Span::detached());
+    assert_eq!(found, expected);
+});
@@ -1,8 +1,11 @@
 use std::fmt::Write;
 
 use typst::foundations::Smart;
+use typst::layout::{Frame, FrameItem};
 use typst::model::{Document, DocumentInfo};
+use typst::visualize::{Color, Paint};
 use typst::World;
+use typst_pdf::PdfOptions;
 
 use crate::collect::Test;
 use crate::world::TestWorld;
@@ -35,6 +38,25 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&Document>) -> String {
             test_eq!(sink, info.author, ["Changed"]);
             test_eq!(sink, info.title.as_deref(), Some("Alternative"));
         }
+        "footnote-continuation-separator" => {
+            // Only the separator on the second page is the red continuation
+            // separator.
+            let red = Paint::from(Color::RED);
+            let counts: Vec<usize> = doc
+                .iter()
+                .flat_map(|doc| &doc.pages)
+                .map(|page| stroked(&page.frame, &red))
+                .take(2)
+                .collect();
+            test_eq!(sink, counts, [0, 1]);
+        }
+        "signature-pdf" => {
+            let pdf = pdf(doc);
+            test_eq!(sink, count(&pdf, b"/FT /Sig"), 2);
+            test_eq!(sink, count(&pdf, b"/T (ceo)"), 1);
+            test_eq!(sink, count(&pdf, b"/T (witness)"), 1);
+            test_eq!(sink, count(&pdf, b"/AcroForm"), 1);
+        }
         _ => {}
     }
     sink
@@ -44,3 +66,31 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&Document>) -> String {
 fn info(doc: Option<&Document>) -> DocumentInfo {
     doc.map(|doc| doc.info.clone()).unwrap_or_default()
 }
+
+/// Count the shapes stroked with the given paint in a frame and its groups.
+fn stroked(frame: &Frame, paint: &Paint) -> usize {
+    frame
+        .items()
+        .map(|(_, item)| match item {
+            FrameItem::Group(group) => stroked(&group.frame, paint),
+            FrameItem::Shape(shape, _) => {
+                shape.stroke.as_ref().is_some_and(|stroke| stroke.paint == *paint)
+                    as usize
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Export the document to PDF, yielding nothing if that fails.
+fn pdf(doc: Option<&Document>) -> Vec<u8> {
+    doc.and_then(|doc| typst_pdf::pdf(doc, &PdfOptions::default()).ok())
+        .unwrap_or_default()
+}
+
+/// Count the occurrences of a pattern in bytes.
+fn count(data: &[u8], pattern: &[u8]) -> usize {
+    data.windows(pattern.len())
+        .filter(|window| *window == pattern)
+        .count()
+}
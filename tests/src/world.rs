@@ -11,7 +11,8 @@ use parking_lot::Mutex;
 use typst::diag::{bail, At, FileError, FileResult, SourceResult, StrResult};
 use typst::engine::Engine;
 use typst::foundations::{
-    func, Array, Bytes, Context, Datetime, IntoValue, NoneValue, Repr, Smart, Value,
+    func, Args, Array, Bytes, Context, Datetime, Func, IntoValue, NoneValue, Repr, Smart,
+    Value,
 };
 use typst::layout::{Abs, Margin, PageElem};
 use typst::model::{Numbering, NumberingPattern};
@@ -187,6 +188,9 @@ fn library() -> Library {
     lib.global.scope_mut().define_func::<test_repr>();
     lib.global.scope_mut().define_func::<print>();
     lib.global.scope_mut().define_func::<lines>();
+    lib.global
+        .scope_mut()
+        .define("host-add", Func::host("host-add", &[], host_add));
     lib.global
         .scope_mut()
         .define("conifer", Color::from_u8(0x9f, 0xEB, 0x52, 0xFF));
@@ -206,6 +210,16 @@ fn library() -> Library {
     lib
 }
 
+/// Adds two integers. Tests functions defined with `Func::host`.
+fn host_add(_: &mut Engine, _: Tracked<Context>, args: &mut Args) -> SourceResult<Value> {
+    let a: i64 = args.expect("a")?;
+    let b: i64 = args.expect("b")?;
+    a.checked_add(b)
+        .map(IntoValue::into_value)
+        .ok_or("the sum is too large")
+        .at(args.span)
+}
+
 #[func]
 fn test(lhs: Value, rhs: Value) -> StrResult<NoneValue> {
     if lhs != rhs {
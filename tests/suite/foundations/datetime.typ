@@ -72,6 +72,14 @@
 #test(datetime(day: 1, month: 3, year: 2001).ordinal(), 31 + 28 + 1);
 #test(datetime(day: 31, month: 12, year: 2001).ordinal(), 365);
 
+--- datetime-week ---
+// Test ISO week numbers.
+#test(datetime(day: 1, month: 1, year: 2021).week(), 53)
+#test(datetime(day: 4, month: 1, year: 2021).week(), 1)
+#test(datetime(day: 30, month: 12, year: 2024).week(), 1)
+#test(datetime(day: 15, month: 6, year: 2023, hour: 12, minute: 0, second: 0).week(), 24)
+#test(datetime(hour: 12, minute: 0, second: 0).week(), none)
+
 --- datetime-display-missing-closing-bracket ---
 // Error: 27-34 missing closing bracket for bracket at index 0
 #datetime.today().display("[year")
@@ -11,9 +11,14 @@
 #eval("RR_1^NN", mode: "math", scope: (RR: math.NN, NN: math.RR))
 
 --- eval-syntax-error-1 ---
-// Error: 7-12 expected pattern
+// Error: 11 expected pattern
 #eval("let")
 
+--- eval-syntax-error-escaped ---
+// With escapes, the error spans the whole string.
+// Error: 7-17 unclosed delimiter
+#eval("f(\"a\"")
+
 --- eval-in-show-rule ---
 #show raw: it => text(font: "PT Sans", eval("[" + it.text + "]"))
 
@@ -28,7 +33,7 @@ Blue #move(dy: -0.15em)[🌊]
 #eval("continue")
 
 --- eval-syntax-error-2 ---
-// Error: 7-12 expected semicolon or line break
+// Error: 9 expected semicolon or line break
 #eval("1 2")
 
 --- eval-path-resolve ---
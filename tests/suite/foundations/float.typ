@@ -1,6 +1,8 @@
 --- float-constructor ---
 #test(float(10), 10.0)
 #test(float(50% * 30%), 0.15)
+#test(float(2.5fr), 2.5)
+#test(float(1fr / 4), 0.25)
 #test(float("31.4e-1"), 3.14)
 #test(float("31.4e\u{2212}1"), 3.14)
 #test(float("3.1415"), 3.1415)
@@ -13,7 +15,7 @@
 #test(type(float(10)), float)
 
 --- float-constructor-bad-type ---
-// Error: 8-13 expected float, boolean, integer, decimal, ratio, or string, found type
+// Error: 8-13 expected float, boolean, integer, decimal, ratio, fraction, or string, found type
 #float(float)
 
 --- float-constructor-bad-value ---
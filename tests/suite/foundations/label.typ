@@ -82,6 +82,7 @@ _Visible_
 #[#[#block()]<e>]<f>
 
 // Error: 1-3 label `<a>` does not exist in the document
+// Hint: 1-3 if you meant to cite a source, add a bibliography to the document
 @a
 
 --- label-unattached-warn ---
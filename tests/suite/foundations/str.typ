@@ -323,6 +323,19 @@
 #test("a123c".split(regex("\d")), ("a", "", "", "c"))
 #test("a123c".split(regex("\d+")), ("a", "c"))
 
+--- string-repeat ---
+// Test the `repeat` method.
+#test("abc".repeat(0), "")
+#test("abc".repeat(2), "abcabc")
+#test("ab".repeat(3), "ab" * 3)
+#test("ab".repeat(3, separator: ", "), "ab, ab, ab")
+#test("ab".repeat(1, separator: ", "), "ab")
+#test("ab".repeat(0, separator: ", "), "")
+
+--- string-repeat-bad-count ---
+// Error: 14-16 number must be at least zero
+#"ab".repeat(-1)
+
 --- string-rev ---
 // Test the `rev` method.
 #test("abc".rev(), "cba")
@@ -20,6 +20,8 @@
 = Introduction <intro>
 
 // Error: 10-25 label `<intro>` occurs multiple times in the document
+// Hint: 10-25 it is attached to 2 elements on page 1
+// Hint: 10-25 use a unique label for each element you want to refer to
 #context locate(<intro>)
 
 --- locate-element-selector ---
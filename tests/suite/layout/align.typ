@@ -136,6 +136,14 @@ To the right! Where the sunlight peeks behind the mountain.
 // Error: 8-30 cannot add a vertical and a 2D alignment
 #align(top + (bottom + right), [A])
 
+--- align-separate-arguments ---
+#test(align(right, bottom)[A].alignment, right + bottom)
+#test(align(top, center)[A].alignment, center + top)
+
+--- align-separate-arguments-two-horizontal ---
+// Error: 14-19 cannot add two horizontal alignments
+#align(left, right)[A]
+
 --- issue-1398-line-align ---
 // Test right-aligning a line and a rectangle.
 #align(right, line(length: 30%))
@@ -6,3 +6,13 @@
 #test(2deg.deg(), 2.0)
 #test(2.94deg.deg(), 2.94)
 #test(0rad.deg(), 0.0)
+
+--- angle-normalize ---
+// Test the `normalize` method.
+#let deg(angle) = calc.round(angle.normalize().deg(), digits: 6)
+#test(deg(0deg), 0.0)
+#test(deg(90deg), 90.0)
+#test(deg(-90deg), 270.0)
+#test(deg(450deg), 90.0)
+#test(calc.round(7rad.normalize().rad(), digits: 6), calc.round(7 - 2 * calc.pi, digits: 6))
+#test((-1e-17 * 1rad).normalize().rad(), 0.0)
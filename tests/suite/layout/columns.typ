@@ -129,3 +129,12 @@ Hallo
 A
 #colbreak(weak: true)
 B
+
+--- columns-balance ---
+#context {
+  let body = columns(2, lorem(20))
+  let balanced = columns(2, balance: true, lorem(20))
+  let full = measure(body, width: 200pt, height: 200pt)
+  let half = measure(balanced, width: 200pt, height: 200pt)
+  assert(half.height < full.height)
+}
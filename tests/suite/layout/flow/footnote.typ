@@ -55,6 +55,17 @@ Beautiful footnotes. #footnote[Wonderful, aren't they?]
   #footnote[Z]
 ]
 
+--- footnote-continuation-separator ---
+// The rest of a long footnote on the next page gets the continuation
+// separator, while the separator on the first page stays regular.
+#set page(height: 100pt)
+#set footnote.entry(
+  continuation-separator: line(length: 100%, stroke: 0.5pt + red),
+)
+#lines(2)
+#footnote(lines(8, "1"))
+#footnote[Short]
+
 --- footnote-break-across-pages-float ---
 #set page(height: 180pt)
 
@@ -124,6 +124,7 @@
 
 --- grid-footer-multiple ---
 // Error: 4:3-4:19 cannot have more than one footer
+// Hint: 4:3-4:19 a single footer can span multiple rows
 #grid(
   [a],
   grid.footer([a]),
@@ -126,6 +126,7 @@
 
 --- grow-header-multiple ---
 // Error: 3:3-3:19 cannot have more than one header
+// Hint: 3:3-3:19 a single header can span multiple rows
 #grid(
   grid.header([a]),
   grid.header([b]),
@@ -67,8 +67,14 @@ Lריווח #h(1cm) R
 --- bidi-vertical ---
 // Test setting a vertical direction.
 // Error: 16-19 text direction must be horizontal
+// Hint: 16-19 vertical writing modes are not supported yet
 #set text(dir: ttb)
 
+--- bidi-direction-string ---
+// Error: 16-21 expected direction or auto, found string
+// Hint: 16-21 directions are not strings, try `rtl` instead
+#set text(dir: "rtl")
+
 --- issue-1373-bidi-tofus ---
 // Test that shaping missing characters in both left-to-right and
 // right-to-left directions does not cause a crash.
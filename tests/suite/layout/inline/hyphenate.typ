@@ -158,10 +158,14 @@ el objetivo de protegerle de las patrullas de milicianos.
 #set text(costs: (hyphenation: auto))
 
 --- costs-invalid-key ---
-// Error: 18-52 unexpected key "invalid-key", valid keys are "hyphenation", "runt", "widow", and "orphan"
+// Error: 18-52 unexpected key "invalid-key", valid keys are "hyphenation", "dash", "runt", "widow", and "orphan"
 #set text(costs: (hyphenation: 1%, invalid-key: 3%))
 
 --- costs-access ---
 #set text(costs: (hyphenation: 1%, runt: 2%))
 #set text(costs: (widow: 3%))
-#context test(text.costs, (hyphenation: 1%, runt: 2%, widow: 3%, orphan: 100%))
+#context test(text.costs, (hyphenation: 1%, dash: 100%, runt: 2%, widow: 3%, orphan: 100%))
+
+--- costs-dash-access ---
+#set text(costs: (dash: 500%))
+#context test(text.costs.dash, 500%)
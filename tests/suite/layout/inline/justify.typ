@@ -172,3 +172,8 @@ int main() {
 // Test that overflow does not lead to bad bounds in paragraph optimization.
 #set par(justify: true)
 #block(width: 0pt)[A B]
+
+--- justify-as-alignment ---
+// Error: 12-19 unknown variable: justify
+// Hint: 12-19 to justify text, use `set par(justify: true)`
+#set align(justify)
@@ -63,6 +63,11 @@ fi vs. #text(features: (liga: 0))[No fi]
 // Error: 21-35 expected string, found boolean
 #set text(features: ("tag", false))
 
+--- text-features-bad-tag ---
+// Error: 21-36 invalid font feature tag "small-caps"
+// Hint: 21-36 feature tags consist of up to four characters, like "smcp"
+#set text(features: ("small-caps",))
+
 --- text-tracking-negative ---
 // Test tracking.
 #set text(tracking: -0.01em)
@@ -92,3 +97,43 @@ My text has spaces.
 // Test word spacing relative to the font's space width.
 #set text(spacing: 50% + 1pt)
 This is tight.
+
+--- text-sentence-spacing ---
+// Test extra space after the end of a sentence. The helper counts how many
+// spaces are widened by measuring against the extra width of one widened
+// word space.
+#let sentence-ends(body, space: [x x]) = {
+  let unit = measure(text(spacing: 200%, space)).width - measure(space).width
+  let extra = measure(text(sentence-spacing: 300%, body)).width - measure(body).width
+  calc.round(extra / unit / 2, digits: 3)
+}
+
+#context test(sentence-ends[x x], 0)
+#context test(sentence-ends[This is a sentence. Another one! Next.], 2)
+#context test(sentence-ends["A quote." (Aside.) Next.], 2)
+#context test(sentence-ends[See e.g.~the previous line.], 0)
+#context test(sentence-ends[x, x; x: x], 0)
+
+--- text-sentence-spacing-styled ---
+// Sentence ends are only detected within one shaping run. Thus, the space
+// after the strong text stays regular and so does the one after the
+// emphasized abbreviation.
+#let sentence-ends(body, space: [x x]) = {
+  let unit = measure(text(spacing: 200%, space)).width - measure(space).width
+  let extra = measure(text(sentence-spacing: 300%, body)).width - measure(body).width
+  calc.round(extra / unit / 2, digits: 3)
+}
+
+#context test(sentence-ends[*The end.* Next. _Dr._ X.], 1)
+
+--- text-sentence-spacing-rtl ---
+// Sentence ends are also detected in right-to-left text.
+#let sentence-ends(body, space: [x x]) = {
+  let unit = measure(text(spacing: 200%, space)).width - measure(space).width
+  let extra = measure(text(sentence-spacing: 300%, body)).width - measure(body).width
+  calc.round(extra / unit / 2, digits: 3)
+}
+
+#set text(lang: "he")
+#context test(sentence-ends(space: [א ב])[שלום. עולם! זה], 2)
+#context test(sentence-ends(space: [א ב])[שלום, עולם], 0)
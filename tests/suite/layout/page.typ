@@ -26,6 +26,20 @@
 // Flipped predefined paper.
 #[#set page(paper: "a11", flipped: true);Flipped A11]
 
+--- page-set-paper-case-insensitive ---
+#set page(paper: "A4")
+#context test(page.width, 210mm)
+
+--- page-set-paper-unknown ---
+// Error: 18-24 unknown paper size: "a100"
+// Hint: 18-24 paper sizes are named like "a4" or "us-letter"
+#set page(paper: "a100")
+
+--- page-set-paper-us-prefix ---
+// Error: 18-26 unknown paper size: "letter"
+// Hint: 18-26 did you mean "us-letter"?
+#set page(paper: "letter")
+
 --- page-set-in-container ---
 #box[
   // Error: 4-18 page configuration is not allowed inside of containers
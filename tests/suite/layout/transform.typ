@@ -165,3 +165,9 @@ Hello #skewed[World]!\
 
 #set skew(reflow: true)
 Hello #skewed[World]!
+
+--- scale-factor-without-unit ---
+// Error: 11-12 expected ratio, length, or auto, found integer
+// Hint: 11-12 a length needs a unit - did you mean 2pt?
+// Hint: 11-12 a ratio needs a unit - did you mean 200%?
+#scale(x: 2)[A]
@@ -7,6 +7,9 @@ What's up?
 --- document-set-author-date ---
 #set document(author: ("A", "B"), date: datetime.today())
 
+--- document-set-description ---
+#set document(description: [A _short_ summary])
+
 --- document-date-bad ---
 // Error: 21-28 expected datetime, none, or auto, found string
 #set document(date: "today")
@@ -0,0 +1,30 @@
+--- endnote-basic ---
+// The notes are listed without warnings. They are hidden and placed to render
+// nothing, but remain visible to introspection.
+#place(hide[
+  First#endnote[One] and second#endnote[Two].
+
+  #endnotes()
+])
+
+#context test(query(endnote).map(note => note.body), ([One], [Two]))
+#context test(counter(endnote).final(), (2,))
+#context test(query(endnotes).len(), 1)
+
+--- endnote-not-listed ---
+// Warning: 18-31 endnote is never listed
+// Hint: 18-31 call `endnotes()` after the endnote to list it
+#place(hide[Text#endnote[Lost]])
+
+--- endnote-listed-before ---
+// Warning: 30-43 endnote is never listed
+// Hint: 30-43 call `endnotes()` after the endnote to list it
+#place(hide[#endnotes() Text#endnote[Late]])
+
+--- endnote-missing-body ---
+// Error: 2-11 missing argument: body
+#endnote()
+
+--- endnotes-unexpected-argument ---
+// Error: 11-12 unexpected argument
+#endnotes(1)
@@ -82,6 +82,19 @@ We can clearly see that @fig-cylinder and
 // Hint: 2-27 you can enable floating placement with `figure(placement: auto, ..)`
 #figure(scope: "parent")[]
 
+--- figure-alt ---
+// The checks happen in the show rule, so the figure is hidden and placed
+// to render nothing.
+#show image: it => test(it.alt, "A tiger")
+#place(hide(figure(image("/assets/images/tiger.jpg"), alt: "A tiger")))
+
+--- figure-alt-image-precedence ---
+#show image: it => test(it.alt, "A striped tiger")
+#place(hide(figure(
+  image("/assets/images/tiger.jpg", alt: "A striped tiger"),
+  alt: "A tiger",
+)))
+
 --- figure-theorem ---
 // Testing show rules with figures with a simple theorem display
 #show figure.where(kind: "theorem"): it => {
@@ -212,6 +225,12 @@ We can clearly see that @fig-cylinder and
   supplement: [B],
 )
 
+--- figure-kind-without-supplement ---
+// Error: 2-24 please specify the figure's supplement
+// Hint: 2-24 custom figure kinds have no default supplement
+// Hint: 2-24 alternatively, set `numbering: none` to disable numbering
+#figure(kind: "foo")[A]
+
 --- figure-caption-position ---
 #set figure.caption(position: top)
 
@@ -74,4 +74,6 @@ Text <hey>
 Text <hey>
 Text <hey>
 // Error: 2-20 label `<hey>` occurs multiple times in the document
+// Hint: 2-20 it is attached to 2 elements on page 1
+// Hint: 2-20 use a unique label for each element you want to refer to
 #link(<hey>)[Nope.]
@@ -108,3 +108,16 @@
 --- numbering-negative ---
 // Error: 17-19 number must be at least zero
 #numbering("1", -1)
+
+--- numbering-symbol-list ---
+#let suits = ("♠", "♥", "♦")
+#test(numbering(suits, 0), "-")
+#test(numbering(suits, 1), "♠")
+#test(numbering(suits, 3), "♦")
+#test(numbering(suits, 4), "♠♠")
+#test(numbering(suits, 8), "♥♥♥")
+#test(numbering(suits, 1, 2), "♥")
+
+--- numbering-symbol-list-empty ---
+// Error: 12-14 symbol list must contain at least one symbol
+#numbering((), 1)
@@ -151,6 +151,8 @@ A
 
 --- outline-bad-element ---
 // Error: 2-27 cannot outline metadata
+// Hint: 2-27 only headings, figures, and equations can be outlined
+// Hint: 2-27 to outline other content, wrap it in a `figure` with a custom `kind`
 #outline(target: metadata)
 #metadata("hello")
 
@@ -64,6 +64,30 @@ Why would anybody ever ...
 
 ... want spacing and indent?
 
+--- par-first-line-indent-all ---
+// Without `all`, a paragraph that doesn't follow another one isn't indented.
+// With it, it is.
+#let width(indent) = measure({
+  set par(first-line-indent: indent)
+  par[x]
+}).width
+
+#context test(width(1em), width(0pt))
+#context test(
+  calc.round((width((amount: 1em, all: true)) - width(0pt)).pt(), digits: 3),
+  calc.round(1em.to-absolute().pt(), digits: 3),
+)
+
+--- par-first-line-indent-all-bad-key ---
+// Error: 29-54 unexpected key "alll", valid keys are "amount" and "all"
+#set par(first-line-indent: (amount: 1em, alll: true))
+
+--- par-first-line-indent-value ---
+#set par(first-line-indent: 12pt)
+#context test(par.first-line-indent, 12pt)
+#set par(first-line-indent: (amount: 12pt, all: true))
+#context test(par.first-line-indent, (amount: 12pt, all: true))
+
 --- par-hanging-indent ---
 // Test hanging indent.
 #set par(hanging-indent: 15pt, justify: true)
@@ -11,6 +11,7 @@ As seen in @intro, we proceed.
 
 --- ref-label-missing ---
 // Error: 1-5 label `<foo>` does not exist in the document
+// Hint: 1-5 if you meant to cite a source, add a bibliography to the document
 @foo
 
 --- ref-label-duplicate ---
@@ -18,6 +19,8 @@ As seen in @intro, we proceed.
 = Second <foo>
 
 // Error: 1-5 label `<foo>` occurs multiple times in the document
+// Hint: 1-5 it is attached to 2 elements on page 1
+// Hint: 1-5 use a unique label for each element you want to refer to
 @foo
 
 --- ref-supplements ---
@@ -85,3 +88,15 @@ Text seen on #ref(<text>, form: "page", supplement: "Page").
 // Test reference with non-whitespace before it.
 #figure[] <1>
 #test([(#ref(<1>))], [(@1)])
+
+--- ref-label-duplicate-pages ---
+= First <bar>
+#pagebreak()
+= Second <bar>
+#pagebreak()
+= Third <bar>
+
+// Error: 1-5 label `<bar>` occurs multiple times in the document
+// Hint: 1-5 it is attached to 3 elements on pages 1, 2, and 3
+// Hint: 1-5 use a unique label for each element you want to refer to
+@bar
@@ -0,0 +1,17 @@
+// Test signature fields.
+
+--- signature-fields ---
+#let sig = signature("ceo", width: 4cm)
+#test(sig.name, "ceo")
+#test(sig.width, 4cm)
+#test(sig.has("height"), false)
+
+--- signature-pdf ---
+// The exported fields are checked in the PDF through a custom check. The
+// placeholders are hidden and placed so that nothing renders.
+#place(hide(signature("ceo")))
+#place(dx: 2cm, hide(signature("witness", width: 3cm)))
+
+--- signature-missing-name ---
+// Error: 2-13 missing argument: name
+#signature()
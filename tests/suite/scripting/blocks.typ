@@ -144,3 +144,13 @@
 
 // Error: 2-3 unknown variable: x
 #x
+
+--- unknown-variable-scoped-definition ---
+// Error: 2-6 unknown variable: item
+// Hint: 2-6 did you mean `enum.item`, `list.item`, or `terms.item`?
+#item[A]
+
+--- unknown-variable-scoped-function ---
+// Error: 3-7 unknown variable: sqrt
+// Hint: 3-7 did you mean `calc.sqrt` or `math.sqrt`?
+#(sqrt(4))
@@ -94,6 +94,25 @@
 // Error: 8 expected comma
 #func(1 2)
 
+--- call-args-garbage-recovery ---
+// Error: 9-15 unexpected arrow
+#func(1 => 2 x, 3)
+
+--- call-args-garbage-recovery-nested ---
+// Error: 9-20 unexpected arrow
+#func(1 => (2, 3) x)
+
+--- call-args-garbage-recovery-content-block ---
+// The content block is parsed as markup, so its parenthesis doesn't count.
+// Error: 9-20 unexpected arrow
+#func(1 => [a) b] x, 3)
+
+--- call-args-garbage-recovery-keeps-errors ---
+// Error: 9-11 unexpected arrow
+// Error: 12-15 invalid number suffix: pp
+// Error: 16-17 unexpected identifier
+#func(1 => 2pp x)
+
 --- call-args-bad-name-and-incomplete-pair ---
 // Error: 7-8 expected identifier, found integer
 // Error: 9 expected expression
@@ -206,3 +225,30 @@
 // Error: 6-7 unclosed delimiter
 // Error: 1:7-2:1 unclosed string
 #func("]
+
+--- call-args-abbreviated-named ---
+// Error: 7-13 unexpected argument: w
+// Hint: 7-13 did you mean `width`?
+#rect(w: 1cm)
+
+--- set-args-abbreviated-named ---
+// Error: 11-19 unexpected argument: fil
+// Hint: 11-19 did you mean `fill`?
+#set text(fil: red)
+
+--- call-host-function ---
+#test(host-add(1, 2), 3)
+#test(host-add.with(1)(2), 3)
+#test(repr(host-add), "host-add")
+
+--- call-host-function-error ---
+// Error: 2-34 the sum is too large
+#host-add(9223372036854775807, 1)
+
+--- call-host-function-missing-argument ---
+// Error: 2-13 missing argument: b
+#host-add(1)
+
+--- call-host-function-unexpected-argument ---
+// Error: 17-18 unexpected argument
+#host-add(1, 2, 3)
@@ -126,6 +126,7 @@
   let f(x) = x + 1
 
   // Error: 8-13 unexpected argument
+  // Error: 15-22 unexpected argument
   f(1, "two", () => x)
 }
 
@@ -221,3 +222,8 @@
 #let x = 1
 #let c = [#(x) => (1, 2)]
 #test(c.children.last(), [(1, 2)]))
+
+--- closure-define-with-def ---
+// Error: 2-5 unknown variable: def
+// Hint: 2-5 to define a function, use a let binding with parameters: `#let name(param) = [..]`
+#def greeting(name)[Hello!]
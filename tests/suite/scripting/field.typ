@@ -32,6 +32,16 @@
 // Error: 9-13 cannot access fields on type boolean
 #{false.true}
 
+--- field-method-without-parentheses ---
+// Error: 9-12 cannot access fields on type array
+// Hint: 9-12 to call the method `len`, add parentheses: `len()`
+#(1, 2).len
+
+--- field-method-without-parentheses-dict ---
+// Error: 15-19 dictionary does not contain key "keys"
+// Hint: 15-19 to call the method `keys`, add parentheses: `keys()`
+#(a: 1, b: 2).keys
+
 --- field-invalid-none ---
 #{
   let object = none
@@ -274,6 +274,11 @@
 // Error: 23-35 unresolved import
 #import "module.typ": non_existing
 
+--- import-item-not-found-similar ---
+// Error: 23-27 unresolved import
+// Hint: 23-27 did you mean `push`?
+#import "module.typ": PUSH
+
 --- import-cyclic ---
 // Cyclic import of this very file.
 // Error: 9-23 cyclic import
@@ -28,3 +28,8 @@
 --- include-semicolon-or-linebreak ---
 // Error: 18 expected semicolon or line break
 #include "hi.typ" Hi
+
+--- include-cyclic ---
+// Error: 10-23 cyclic include
+// Hint: 10-23 a file cannot include itself, neither directly nor through other files
+#include "include.typ"
@@ -47,6 +47,23 @@ Three
 #let ůñıćóðė = 1
 #test(ůñıćóðė, 1)
 
+--- let-valid-idents-unicode ---
+// Test that non-ASCII identifiers work the same everywhere.
+#let größe = 12pt
+#let 中文-名字 = 1
+#test(größe, 12pt)
+#test(中文-名字, 1)
+
+// As parameters and named arguments.
+#let f(größe: 1, 中文) = größe + 中文
+#test(f(größe: 2, 3), 5)
+
+// As dictionary keys.
+#let dict = (größe: 1, 中文: 2)
+#test(dict.größe, 1)
+#test(dict.at("中文"), 2)
+#test(repr(dict), "(größe: 1, 中文: 2)")
+
 --- let-binding-keyword-in-markup ---
 // Error: 6-8 expected pattern, found keyword `as`
 // Hint: 6-8 keyword `as` is not allowed as an identifier; try `as_` instead
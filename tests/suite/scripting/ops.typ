@@ -432,8 +432,19 @@
 
 --- ops-subtract-int-from-ratio ---
 // Error: 13-20 cannot subtract integer from ratio
+// Hint: 13-20 to give the number a unit, write `1%`
 #((1234567, 40% - 1))
 
+--- ops-add-int-and-length ---
+// Error: 3-12 cannot add integer and length
+// Hint: 3-12 to give the number a unit, write `2pt`
+#(2 + 1.5em)
+
+--- ops-subtract-angle-from-float ---
+// Error: 3-15 cannot subtract angle from float
+// Hint: 3-15 to give the number a unit, write `1.5deg`
+#(1.5 - 90deg)
+
 --- ops-multiply-int-with-bool ---
 // Error: 3-11 cannot multiply integer with boolean
 #(2 * true)
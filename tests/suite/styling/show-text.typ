@@ -57,6 +57,7 @@ Treeworld, the World of worlds, is a world.
 
 --- show-text-regex-matches-empty ---
 // Error: 1:7-1:42 regex matches empty text
+// Hint: 1:7-1:42 make sure that the regex matches at least one character, for example by using `+` instead of `*`
 #show regex("(VAR_GLOBAL|END_VAR||BOOL)") : []
 
 --- show-text-regex-character-class ---
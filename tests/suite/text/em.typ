@@ -31,3 +31,7 @@ G // 5pt
 }
 
 #stack(dir: ltr, spacing: 1fr, square(size: size), square(size: 25pt))
+
+--- text-size-negative-warning ---
+// Warning: 17-21 text size is negative
+#set text(size: -1em)
@@ -77,6 +77,12 @@ I
 #let var = text(font: ("list-of", "nonexistent-fonts"))[don't]
 #var
 
+--- text-unknown-font-family-variant ---
+// Warning: 17-40 unknown font family: libertinus serif bold
+// Hint: 17-40 did you mean `"libertinus serif"`?
+// Hint: 17-40 font variants are selected with the `weight` and `style` parameters
+#set text(font: "Libertinus Serif Bold")
+
 --- text-font-linux-libertine ---
 // Warning: 17-34 Typst's default font has changed from Linux Libertine to its successor Libertinus Serif
 // Hint: 17-34 please set the font to `"Libertinus Serif"` instead
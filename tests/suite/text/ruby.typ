@@ -0,0 +1,34 @@
+--- ruby-fields ---
+// Test that the base text and annotation are accessible as fields.
+#let r = ruby[東京][とうきょう]
+#test(r.body, [東京])
+#test(r.annotation, [とうきょう])
+
+--- ruby-missing-annotation ---
+// Error: 2-12 missing argument: annotation
+#ruby[base]
+
+--- ruby-basic ---
+// The ruby is as wide as its base and stacks the annotation above it with a
+// gap. Both end at their baseline.
+#let pt(length) = calc.round(length.pt(), digits: 3)
+#set text(bottom-edge: "baseline")
+#context {
+  let base = measure[東京]
+  let annotation = measure(text(size: 0.5em)[とうきょう])
+  let size = measure(ruby[東京][とうきょう])
+  test(pt(size.width), pt(base.width))
+  test(size.height > base.height + annotation.height, true)
+}
+
+--- ruby-wide-annotation ---
+// The annotation is wider than its base, so the ruby is as wide as the
+// annotation.
+#let pt(length) = calc.round(length.pt(), digits: 3)
+#context {
+  let base = measure[日]
+  let annotation = measure(text(size: 0.5em)[にち・じつ])
+  let size = measure(ruby[日][にち・じつ])
+  test(annotation.width > base.width, true)
+  test(pt(size.width), pt(annotation.width))
+}
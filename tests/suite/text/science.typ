@@ -0,0 +1,37 @@
+--- si-formatting ---
+#import science: si
+#test(si(9.81, "m/s^2"), [#9.81#"\u{202F}"m#"/"s#super[2]])
+#test(si(12, "N.m"), [#12#"\u{202F}"N#"⋅"m])
+#test(si(3, "kg m s^-2"), [#3#"\u{202F}"kg#"\u{202F}"m#"\u{202F}"s#super[−2]])
+
+--- chem-formatting ---
+#import science: chem
+#test(chem("H2O"), [H#sub[2]O])
+#test(chem("2H2"), [2H#sub[2]])
+#test(chem("Ca(OH)2"), [Ca(OH)#sub[2]])
+#test(chem("SO4^2-"), [SO#sub[4]#super[2−]])
+#test(chem("Na^+"), [Na#super("+")])
+
+--- si-empty-unit ---
+#import science: si
+// Error: 8-10 unit must not be empty
+#si(1, "")
+
+--- si-missing-power ---
+#import science: si
+// Error: 11-17 expected power at index 4
+#si(9.81, "m/s^")
+
+--- si-trailing-division ---
+#import science: si
+// Error: 8-12 expected unit at index 2
+#si(5, "m/")
+
+--- chem-missing-charge ---
+#import science: chem
+// Error: 7-13 expected charge at index 4
+#chem("SO4^")
+
+--- science-not-global ---
+// Error: 2-4 unknown variable: si
+#si(1, "m")
@@ -327,3 +327,13 @@
 // output with a PDF comparison script.
 #rect(fill: red.transparentize(50%))
 #image("/assets/images/tiger.jpg", width: 45pt)
+
+--- color-rgba-unknown ---
+// Error: 2-6 unknown variable: rgba
+// Hint: 2-6 to specify an alpha component, pass it as the last argument to `rgb`
+#rgba(255, 0, 0, 50%)
+
+--- color-lighten-unknown ---
+// Error: 2-9 unknown variable: lighten
+// Hint: 2-9 `lighten` is a method of colors, try calling it on one: `red.lighten(..)`
+#lighten(red, 20%)
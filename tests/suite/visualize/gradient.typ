@@ -658,3 +658,8 @@ $ A = mat(
   height: 10pt,
   fill: gradient.linear(violet, blue, space: cmyk)
 )
+
+--- gradient-linear-unknown ---
+// Error: 13-19 unknown variable: linear
+// Hint: 13-19 to create a gradient, use `gradient.linear(..)`
+#rect(fill: linear(red, blue))
@@ -90,3 +90,8 @@
 --- line-bad-point-component-type ---
 // Error: 14-26 expected relative length, found angle
 #line(start: (3deg, 10pt), length: 5cm)
+
+--- line-bad-length-without-unit ---
+// Error: 15-16 expected relative length, found integer
+// Hint: 15-16 a length needs a unit - did you mean 5pt?
+#line(length: 5)